@@ -13,30 +13,148 @@ COLORS = {
     'simple_soft_float': '#2ca02c',
     'softfloat_sys': '#d62728',
     'softfloat_pure': '#9467bd',
-    'const_soft_float': '#8c564b'
+    'const_soft_float': '#8c564b',
+    'half': '#e377c2',
+    'compiler_builtins': '#bcbd22',
+    'native': '#7f7f7f'
 }
 
+# Backend timing the primitive float types, the baseline of the slowdown chart
+BASELINE = 'native'
+
 def parse_benchmark_data(raw_data):
     """Parse the benchmark output format into structured data."""
     lines = raw_data.strip().split('\n')
     benchmarks = []
 
-    pattern = r'test ([^:]+)::([^_]+)_([^ ]+)\s+\.\.\. bench:\s+([\d,\.]+) ns/iter'
+    pattern = r'test ([^:]+)::([^:]+)::([^:]+)::([^: ]+)\s+\.\.\. bench:\s+([\d,\.]+) ns/iter'
 
     for line in lines:
         match = re.match(pattern, line)
         if match:
-            precision, operation, library, time_str = match.groups()
+            precision, operation, mode, library, time_str = match.groups()
+            # profile::<platform>::<format>::<library> and
+            # f8::<format>::<operation>::<library> have as many parts
+            if precision in ('profile', 'f8'):
+                continue
             time = float(time_str.replace(',', ''))
             benchmarks.append({
                 'precision': precision,
                 'operation': operation,
+                'mode': mode,
                 'library': library,
                 'time': time
             })
 
     return benchmarks
 
+def parse_set_data(raw_data, kind):
+    """Parse the benchmarks over operand sets of one kind (corpus or stream), which add the set to the path."""
+    benchmarks = []
+
+    pattern = r'test ' + kind + r'::([^:]+)::([^:]+)::([^:]+)::([^: ]+)\s+\.\.\. bench:\s+([\d,\.]+) ns/iter'
+
+    for line in raw_data.strip().split('\n'):
+        match = re.match(pattern, line)
+        if match:
+            precision, operation, operand_set, library, time_str = match.groups()
+            benchmarks.append({
+                'precision': precision,
+                'operation': operation,
+                'set': operand_set,
+                'library': library,
+                'time': float(time_str.replace(',', ''))
+            })
+
+    return benchmarks
+
+def create_set_visualization(benchmarks, kind, output_dir='benchmark_graphs'):
+    """Create one chart per precision and operation, grouping libraries by operand set."""
+    os.makedirs(output_dir, exist_ok=True)
+
+    precisions = sorted(list(set(b['precision'] for b in benchmarks)))
+    operations = sorted(list(set(b['operation'] for b in benchmarks)))
+    libraries = sorted(list(set(b['library'] for b in benchmarks)))
+    # Keep the sets in the order they are benchmarked
+    sets = list(dict.fromkeys(b['set'] for b in benchmarks))
+
+    plt.style.use('ggplot')
+
+    for precision in precisions:
+        for operation in operations:
+            data = {(b['set'], b['library']): b['time'] for b in benchmarks
+                    if b['precision'] == precision and b['operation'] == operation}
+            if not data:
+                continue
+
+            plt.figure(figsize=(14, 8))
+            bar_width = 0.8 / len(libraries)
+            for i, library in enumerate(libraries):
+                plt.bar(
+                    [j + i * bar_width for j in range(len(sets))],
+                    [data.get((s, library), 0) for s in sets],
+                    bar_width,
+                    label=library.replace('_', ' '),
+                    color=COLORS.get(library, f'C{i}')
+                )
+
+            plt.xlabel(f'Operand set ({kind})', fontsize=14)
+            plt.ylabel('Time (ns/iter)', fontsize=14)
+            plt.title(f'{precision} {operation.capitalize()} Performance by Operand Set', fontsize=16)
+            plt.xticks([j + 0.4 - bar_width / 2 for j in range(len(sets))], sets)
+            plt.legend()
+            plt.yscale('log')
+            plt.grid(True, which="both", ls="-", alpha=0.2)
+            plt.gca().yaxis.set_major_formatter(ScalarFormatter())
+
+            plt.tight_layout()
+            plt.savefig(f"{output_dir}/{kind}_{precision}_{operation}.png", dpi=300)
+            plt.close()
+
+def create_slowdown_visualization(benchmarks, output_dir='benchmark_graphs'):
+    """Create one chart per operation of each library's time in rne divided by the baseline's, per precision."""
+    os.makedirs(output_dir, exist_ok=True)
+
+    benchmarks = [b for b in benchmarks if b['mode'] == 'rne']
+    baseline = {(b['precision'], b['operation']): b['time'] for b in benchmarks
+                if b['library'] == BASELINE}
+    libraries = sorted(set(b['library'] for b in benchmarks) - {BASELINE})
+    operations = sorted(set(operation for _, operation in baseline))
+
+    plt.style.use('ggplot')
+
+    for operation in operations:
+        # Only precisions the baseline covers have a factor
+        precisions = sorted(p for p, o in baseline if o == operation)
+        data = {(b['precision'], b['library']): b['time'] / baseline[(b['precision'], operation)]
+                for b in benchmarks
+                if b['operation'] == operation and (b['precision'], operation) in baseline}
+
+        plt.figure(figsize=(12, 8))
+        bar_width = 0.8 / len(libraries)
+        for i, library in enumerate(libraries):
+            plt.bar(
+                [j + i * bar_width for j in range(len(precisions))],
+                [data.get((p, library), 0) for p in precisions],
+                bar_width,
+                label=library.replace('_', ' '),
+                color=COLORS.get(library, f'C{i}')
+            )
+
+        plt.axhline(y=1, color=COLORS[BASELINE], linestyle='--', label=BASELINE)
+        plt.xlabel('Precision', fontsize=14)
+        plt.ylabel(f'Slowdown (x {BASELINE})', fontsize=14)
+        plt.title(f'{operation.capitalize()} (rne) Slowdown Relative to {BASELINE.capitalize()}', fontsize=16)
+        plt.xticks([j + 0.4 - bar_width / 2 for j in range(len(precisions))], precisions)
+        plt.legend()
+        plt.yscale('log')
+        plt.grid(True, which="both", ls="-", alpha=0.2)
+        plt.gca().yaxis.set_major_formatter(ScalarFormatter())
+
+        plt.tight_layout()
+        plt.savefig(f"{output_dir}/slowdown_{operation}.png", dpi=300)
+        plt.close()
+
 def create_visualization(benchmarks, output_dir='benchmark_graphs'):
     """Create visualizations for the benchmark data with improved visual grouping."""
     # Ensure output directory exists
@@ -45,15 +163,16 @@ def create_visualization(benchmarks, output_dir='benchmark_graphs'):
     # Extract unique values
     precisions = sorted(list(set(b['precision'] for b in benchmarks)))
     operations = sorted(list(set(b['operation'] for b in benchmarks)))
+    modes = sorted(list(set(b['mode'] for b in benchmarks)))
     libraries = sorted(list(set(b['library'] for b in benchmarks)))
 
     # Set the style
     plt.style.use('ggplot')
 
-    # Create plots for each operation
-    for operation in operations:
-        # Filter data for this operation
-        op_data = [b for b in benchmarks if b['operation'] == operation]
+    # Create plots for each operation and rounding mode
+    for operation, mode in [(o, m) for o in operations for m in modes]:
+        # Filter data for this operation and rounding mode
+        op_data = [b for b in benchmarks if b['operation'] == operation and b['mode'] == mode]
 
         # Prepare data for plotting
         plot_data = {}
@@ -105,7 +224,7 @@ def create_visualization(benchmarks, output_dir='benchmark_graphs'):
         # Set up the axes
         plt.xlabel('Precision', fontsize=14)
         plt.ylabel('Time (ns/iter)', fontsize=14)
-        plt.title(f'{operation.capitalize()} Operation Performance by Library and Precision', fontsize=16)
+        plt.title(f'{operation.capitalize()} ({mode}) Operation Performance by Library and Precision', fontsize=16)
         plt.xticks(tick_positions, precisions)
 
         # Add visual separation between groups
@@ -145,10 +264,11 @@ def create_visualization(benchmarks, output_dir='benchmark_graphs'):
 
         # Adjust layout and save
         plt.tight_layout()
-        plt.savefig(f"{output_dir}/{operation}_benchmark.png", dpi=300)
+        plt.savefig(f"{output_dir}/{operation}_{mode}_benchmark.png", dpi=300)
         plt.close()
 
-    # Create a combined visualization with subplots
+    # Create a combined visualization with subplots, in the default rounding mode
+    benchmarks = [b for b in benchmarks if b['mode'] == 'rne']
     fig, axes = plt.subplots(nrows=len(operations), figsize=(14, 5 * len(operations)))
 
     for i, operation in enumerate(operations):
@@ -253,8 +373,13 @@ def main():
 
     # Parse and visualize
     benchmarks = parse_benchmark_data(raw_data)
+    for kind in ['corpus', 'stream']:
+        set_benchmarks = parse_set_data(raw_data, kind)
+        if set_benchmarks:
+            create_set_visualization(set_benchmarks, kind, args.output)
     if benchmarks:
         create_visualization(benchmarks, args.output)
+        create_slowdown_visualization(benchmarks, args.output)
         print(f"Visualizations saved to {args.output}/ directory")
     else:
         print("No valid benchmark data found")
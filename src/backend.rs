@@ -0,0 +1,449 @@
+//! A common interface over the benchmarked soft-float libraries.
+//!
+//! Every library is wrapped by a zero-sized adapter implementing
+//! [`SoftFloatBackend`] once per format it supports. Operands and results
+//! cross the interface as raw bit patterns, so all libraries can be fed and
+//! checked identically.
+
+use crate::env::{FpEnv, RoundingMode};
+use crate::format::{
+    BFloat16, Binary128, Binary16, Binary32, Binary64, Format, Int, X87DoubleExtended,
+};
+use crate::platform::Platform;
+use std::cmp::Ordering;
+
+mod compiler_builtins;
+mod const_soft_float;
+mod half;
+mod native;
+mod rug;
+mod rustc_apfloat;
+mod simple_soft_float;
+mod softfloat_pure;
+mod softfloat_sys;
+
+pub use self::compiler_builtins::CompilerBuiltins;
+pub use self::const_soft_float::ConstSoftFloat;
+pub use self::half::Half;
+pub use self::native::Native;
+pub use self::rug::Rug;
+pub use self::rustc_apfloat::RustcApfloat;
+pub use self::simple_soft_float::SimpleSoftFloat;
+pub use self::softfloat_pure::SoftfloatPure;
+pub use self::softfloat_sys::SoftfloatSys;
+
+/// A benchmarked library.
+pub trait Backend {
+    /// Library name as used in benchmark paths and reports.
+    const NAME: &'static str;
+}
+
+/// Arithmetic on format `F` as implemented by a soft-float library.
+pub trait SoftFloatBackend<F: Format>: Backend {
+    /// Library-native representation of a value of format `F`.
+    type Value;
+
+    /// Rounding modes the library can honour. Operations must not be called
+    /// with any other mode in the environment.
+    const ROUNDING_MODES: &'static [RoundingMode] = &RoundingMode::ALL;
+
+    /// Whether operations raise exception flags in the environment. Libraries
+    /// without status reporting leave the flags untouched.
+    const EXCEPTION_FLAGS: bool = true;
+
+    /// Platform profiles the library reproduces exactly, configured from
+    /// [`FpEnv::platform`]. Libraries with fixed tininess detection or NaN
+    /// propagation ignore the profile and list none, or only the one they
+    /// happen to match.
+    const PLATFORMS: &'static [Platform] = &[];
+
+    fn from_bits(bits: F::Bits) -> Self::Value;
+    fn to_bits(value: Self::Value) -> F::Bits;
+
+    fn add(a: Self::Value, b: Self::Value, env: &mut FpEnv) -> Self::Value;
+    fn sub(a: Self::Value, b: Self::Value, env: &mut FpEnv) -> Self::Value;
+    fn mul(a: Self::Value, b: Self::Value, env: &mut FpEnv) -> Self::Value;
+    fn div(a: Self::Value, b: Self::Value, env: &mut FpEnv) -> Self::Value;
+
+    /// Square root, or `None` if the library does not provide one.
+    fn sqrt(_a: Self::Value, _env: &mut FpEnv) -> Option<Self::Value> {
+        None
+    }
+
+    /// Fused multiply-add `a * b + c` with a single rounding, or `None` if the
+    /// library does not provide one.
+    fn fma(
+        _a: Self::Value,
+        _b: Self::Value,
+        _c: Self::Value,
+        _env: &mut FpEnv,
+    ) -> Option<Self::Value> {
+        None
+    }
+
+    /// IEEE 754 `remainder`, i.e. `a - n * b` with `n` the integer nearest
+    /// `a / b`, or `None` if the library does not provide one.
+    fn rem(_a: Self::Value, _b: Self::Value, _env: &mut FpEnv) -> Option<Self::Value> {
+        None
+    }
+
+    /// IEEE 754 `roundToIntegral` in the environment's mode, which unlike
+    /// `roundToIntegralExact` does not signal inexact, or `None` if the
+    /// library does not provide it.
+    fn round_to_integral(_a: Self::Value, _env: &mut FpEnv) -> Option<Self::Value> {
+        None
+    }
+}
+
+/// Conversion from format `F` to format `T` as implemented by a soft-float
+/// library, rounding in the environment's mode when `T` is narrower.
+///
+/// Operands and results cross as bit patterns, since a library may only have
+/// a type of its own for one of the two formats.
+pub trait Convert<F: Format, T: Format>: Backend {
+    /// Rounding modes the library can honour, as for [`SoftFloatBackend`].
+    const ROUNDING_MODES: &'static [RoundingMode] = &RoundingMode::ALL;
+
+    /// Whether the conversion raises exception flags in the environment.
+    const EXCEPTION_FLAGS: bool = true;
+
+    fn convert(bits: F::Bits, env: &mut FpEnv) -> T::Bits;
+}
+
+/// Conversion from the integer type `I` to format `F` as implemented by a
+/// soft-float library, rounding in the environment's mode.
+pub trait FromInt<I: Int, F: Format>: Backend {
+    /// Rounding modes the library can honour, as for [`SoftFloatBackend`].
+    const ROUNDING_MODES: &'static [RoundingMode] = &RoundingMode::ALL;
+
+    /// Whether the conversion raises exception flags in the environment.
+    const EXCEPTION_FLAGS: bool = true;
+
+    fn from_int(value: I, env: &mut FpEnv) -> F::Bits;
+}
+
+/// Conversion from format `F` to the integer type `I` as implemented by a
+/// soft-float library, rounding to an integral value in the environment's
+/// mode.
+///
+/// NaNs and values outside `I`'s range have no correct result, and IEEE 754
+/// only asks for invalid to be signaled. The result is whatever the library
+/// returns for them, or `None` if it returns no integer at all.
+pub trait ToInt<F: Format, I: Int>: Backend {
+    /// Rounding modes the library can honour, as for [`SoftFloatBackend`].
+    const ROUNDING_MODES: &'static [RoundingMode] = &RoundingMode::ALL;
+
+    /// Whether the conversion raises exception flags in the environment.
+    const EXCEPTION_FLAGS: bool = true;
+
+    fn to_int(bits: F::Bits, env: &mut FpEnv) -> Option<I>;
+}
+
+/// Conversion between format `F` and text as implemented by a library.
+pub trait Decimal<F: Format>: Backend {
+    /// Rounding modes `parse` can honour, as for [`SoftFloatBackend`].
+    const ROUNDING_MODES: &'static [RoundingMode] = &RoundingMode::ALL;
+
+    /// Whether `parse` raises exception flags in the environment.
+    const EXCEPTION_FLAGS: bool = true;
+
+    /// Whether `format` prints the fewest significant digits that parse back
+    /// to the value, rather than merely enough of them.
+    const SHORTEST: bool = false;
+
+    /// Parses a decimal literal such as `1.5e-3`, or a hexadecimal one such
+    /// as `0x1.8p3` if the library reads them, rounding it in the
+    /// environment's mode. `None` if the library rejects the text.
+    fn parse(text: &str, env: &mut FpEnv) -> Option<F::Bits>;
+
+    /// Formats a finite value as decimal text that parses back to it.
+    fn format(bits: F::Bits) -> String;
+}
+
+/// Comparisons of format `F` as implemented by a library.
+///
+/// IEEE 754 has quiet comparisons signal invalid only for signaling NaN
+/// operands and signaling comparisons for any NaN. The predicates are named
+/// as in SoftFloat, where `eq` is quiet and `lt` and `le` signal. Libraries
+/// comparing three ways implement `compare_quiet` and `compare_signaling`
+/// and get the predicates from them; libraries with predicates of their own
+/// override those.
+pub trait Compare<F: Format>: Backend {
+    /// Whether the comparisons raise exception flags in the environment.
+    const EXCEPTION_FLAGS: bool = true;
+
+    /// How `a` orders against `b`, or `None` if either is a NaN.
+    fn compare_quiet(a: F::Bits, b: F::Bits, env: &mut FpEnv) -> Option<Ordering>;
+
+    /// [`Compare::compare_quiet`], signaling invalid for quiet NaNs too.
+    fn compare_signaling(a: F::Bits, b: F::Bits, env: &mut FpEnv) -> Option<Ordering>;
+
+    /// compareQuietEqual.
+    fn eq(a: F::Bits, b: F::Bits, env: &mut FpEnv) -> bool {
+        Self::compare_quiet(a, b, env) == Some(Ordering::Equal)
+    }
+
+    /// compareSignalingEqual.
+    fn eq_signaling(a: F::Bits, b: F::Bits, env: &mut FpEnv) -> bool {
+        Self::compare_signaling(a, b, env) == Some(Ordering::Equal)
+    }
+
+    /// compareSignalingLess.
+    fn lt(a: F::Bits, b: F::Bits, env: &mut FpEnv) -> bool {
+        Self::compare_signaling(a, b, env) == Some(Ordering::Less)
+    }
+
+    /// compareQuietLess.
+    fn lt_quiet(a: F::Bits, b: F::Bits, env: &mut FpEnv) -> bool {
+        Self::compare_quiet(a, b, env) == Some(Ordering::Less)
+    }
+
+    /// compareSignalingLessEqual.
+    fn le(a: F::Bits, b: F::Bits, env: &mut FpEnv) -> bool {
+        matches!(
+            Self::compare_signaling(a, b, env),
+            Some(Ordering::Less | Ordering::Equal)
+        )
+    }
+
+    /// compareQuietLessEqual.
+    fn le_quiet(a: F::Bits, b: F::Bits, env: &mut FpEnv) -> bool {
+        matches!(
+            Self::compare_quiet(a, b, env),
+            Some(Ordering::Less | Ordering::Equal)
+        )
+    }
+
+    /// compareQuietUnordered.
+    fn unordered(a: F::Bits, b: F::Bits, env: &mut FpEnv) -> bool {
+        Self::compare_quiet(a, b, env).is_none()
+    }
+}
+
+/// The smaller or larger of two values of format `F` as implemented by a
+/// library, each `None` if the library does not provide it.
+///
+/// IEEE 754-2008's `minNum` and `maxNum` treat a quiet NaN as missing data
+/// and return the other operand, and leave the sign of a zero result open.
+/// IEEE 754-2019 dropped them for `minimum` and `maximum`, which return a
+/// quiet NaN if either operand is one and order `-0` below `+0`.
+pub trait MinMax<F: Format>: Backend {
+    /// Whether the operations raise exception flags in the environment.
+    const EXCEPTION_FLAGS: bool = true;
+
+    fn minimum(_a: F::Bits, _b: F::Bits, _env: &mut FpEnv) -> Option<F::Bits> {
+        None
+    }
+
+    fn maximum(_a: F::Bits, _b: F::Bits, _env: &mut FpEnv) -> Option<F::Bits> {
+        None
+    }
+
+    fn min_num(_a: F::Bits, _b: F::Bits, _env: &mut FpEnv) -> Option<F::Bits> {
+        None
+    }
+
+    fn max_num(_a: F::Bits, _b: F::Bits, _env: &mut FpEnv) -> Option<F::Bits> {
+        None
+    }
+}
+
+/// An operation of [`SoftFloatBackend`], for harnesses choosing operations at
+/// run time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Sqrt,
+    Fma,
+    Rem,
+}
+
+impl Op {
+    pub const ALL: [Op; 7] = [
+        Op::Add,
+        Op::Sub,
+        Op::Mul,
+        Op::Div,
+        Op::Sqrt,
+        Op::Fma,
+        Op::Rem,
+    ];
+
+    /// Name as used in benchmark paths and reports, e.g. `add`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Op::Add => "add",
+            Op::Sub => "sub",
+            Op::Mul => "mul",
+            Op::Div => "div",
+            Op::Sqrt => "sqrt",
+            Op::Fma => "fma",
+            Op::Rem => "rem",
+        }
+    }
+
+    /// Number of operands.
+    pub fn arity(self) -> usize {
+        match self {
+            Op::Sqrt => 1,
+            Op::Add | Op::Sub | Op::Mul | Op::Div | Op::Rem => 2,
+            Op::Fma => 3,
+        }
+    }
+}
+
+type ApplyFn<F> = fn(Op, &[<F as Format>::Bits], &mut FpEnv) -> Option<<F as Format>::Bits>;
+
+/// A backend's implementation of format `F` behind a function pointer, so
+/// harnesses can loop over every library at run time.
+pub struct DynBackend<F: Format> {
+    pub name: &'static str,
+    pub rounding_modes: &'static [RoundingMode],
+    pub exception_flags: bool,
+    pub platforms: &'static [Platform],
+    apply: ApplyFn<F>,
+}
+
+impl<F: Format> DynBackend<F> {
+    pub fn new<B: SoftFloatBackend<F>>() -> DynBackend<F> {
+        DynBackend {
+            name: B::NAME,
+            rounding_modes: B::ROUNDING_MODES,
+            exception_flags: B::EXCEPTION_FLAGS,
+            platforms: B::PLATFORMS,
+            apply: apply::<F, B>,
+        }
+    }
+
+    /// Runs `op` on the first [`Op::arity`] `operands`, or returns `None` if
+    /// the library does not provide it.
+    pub fn apply(&self, op: Op, operands: &[F::Bits], env: &mut FpEnv) -> Option<F::Bits> {
+        (self.apply)(op, operands, env)
+    }
+}
+
+fn apply<F: Format, B: SoftFloatBackend<F>>(
+    op: Op,
+    operands: &[F::Bits],
+    env: &mut FpEnv,
+) -> Option<F::Bits> {
+    let mut values = operands[..op.arity()]
+        .iter()
+        .map(|&bits| B::from_bits(bits));
+    let mut next = || values.next().unwrap();
+    let result = match op {
+        Op::Add => Some(B::add(next(), next(), env)),
+        Op::Sub => Some(B::sub(next(), next(), env)),
+        Op::Mul => Some(B::mul(next(), next(), env)),
+        Op::Div => Some(B::div(next(), next(), env)),
+        Op::Sqrt => B::sqrt(next(), env),
+        Op::Fma => B::fma(next(), next(), next(), env),
+        Op::Rem => B::rem(next(), next(), env),
+    };
+    result.map(B::to_bits)
+}
+
+/// Formats that know every backend implementing them.
+pub trait Backends: Format + Sized {
+    fn backends() -> Vec<DynBackend<Self>>;
+}
+
+macro_rules! impl_backends {
+    ($format:ty: $($backend:ty),*) => {
+        impl Backends for $format {
+            fn backends() -> Vec<DynBackend<$format>> {
+                vec![$(DynBackend::new::<$backend>()),*]
+            }
+        }
+    };
+}
+
+impl_backends!(Binary16: SimpleSoftFloat, SoftfloatSys, Half, Rug, RustcApfloat, Native);
+impl_backends!(
+    Binary32: SimpleSoftFloat,
+    SoftfloatSys,
+    SoftfloatPure,
+    ConstSoftFloat,
+    Rug,
+    RustcApfloat,
+    CompilerBuiltins,
+    Native
+);
+impl_backends!(
+    Binary64: SimpleSoftFloat,
+    SoftfloatSys,
+    SoftfloatPure,
+    ConstSoftFloat,
+    Rug,
+    RustcApfloat,
+    CompilerBuiltins,
+    Native
+);
+impl_backends!(
+    Binary128: SimpleSoftFloat,
+    SoftfloatSys,
+    RustcApfloat,
+    CompilerBuiltins,
+    Native
+);
+impl_backends!(BFloat16: SimpleSoftFloat, Half, Rug, RustcApfloat);
+impl_backends!(X87DoubleExtended: SoftfloatSys, RustcApfloat);
+
+/// A backend's [`ToInt`] from format `F` to `I` behind a function pointer.
+pub struct DynToInt<F: Format, I: Int> {
+    pub name: &'static str,
+    pub exception_flags: bool,
+    to_int: fn(F::Bits, &mut FpEnv) -> Option<I>,
+}
+
+impl<F: Format, I: Int> DynToInt<F, I> {
+    pub fn new<B: ToInt<F, I>>() -> DynToInt<F, I> {
+        DynToInt {
+            name: B::NAME,
+            exception_flags: <B as ToInt<F, I>>::EXCEPTION_FLAGS,
+            to_int: B::to_int,
+        }
+    }
+
+    pub fn to_int(&self, bits: F::Bits, env: &mut FpEnv) -> Option<I> {
+        (self.to_int)(bits, env)
+    }
+}
+
+/// Formats that know every backend converting them to `I`.
+pub trait IntBackends<I: Int>: Format + Sized {
+    fn int_backends() -> Vec<DynToInt<Self, I>>;
+}
+
+macro_rules! impl_int_backends {
+    ($format:ty: $($int:ty),*: $backends:tt) => {
+        $(impl_int_backends!(@int $format, $int, $backends);)*
+    };
+    (@int $format:ty, $int:ty, [$($backend:ty),*]) => {
+        impl IntBackends<$int> for $format {
+            fn int_backends() -> Vec<DynToInt<$format, $int>> {
+                vec![$(DynToInt::new::<$backend>()),*]
+            }
+        }
+    };
+}
+
+impl_int_backends!(
+    Binary16: i32, u32, i64, u64: [SimpleSoftFloat, SoftfloatSys, Rug, RustcApfloat, Native]
+);
+impl_int_backends!(Binary16: i128, u128: [SimpleSoftFloat, Rug, RustcApfloat, Native]);
+impl_int_backends!(
+    Binary32: i32, u32, i64, u64: [SimpleSoftFloat, SoftfloatSys, Rug, RustcApfloat, Native]
+);
+impl_int_backends!(Binary32: i128, u128: [SimpleSoftFloat, Rug, RustcApfloat, Native]);
+impl_int_backends!(
+    Binary64: i32, u32, i64, u64: [SimpleSoftFloat, SoftfloatSys, Rug, RustcApfloat, Native]
+);
+impl_int_backends!(Binary64: i128, u128: [SimpleSoftFloat, Rug, RustcApfloat, Native]);
+impl_int_backends!(
+    Binary128: i32, u32, i64, u64: [SimpleSoftFloat, SoftfloatSys, RustcApfloat, Native]
+);
+impl_int_backends!(Binary128: i128, u128: [SimpleSoftFloat, RustcApfloat, Native]);
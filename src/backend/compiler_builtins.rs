@@ -0,0 +1,199 @@
+use super::{Backend, Compare, Convert, SoftFloatBackend};
+use crate::env::{FpEnv, RoundingMode};
+use crate::format::{Binary128, Binary32, Binary64, Format};
+use std::cmp::Ordering;
+
+/// [compiler-builtins](https://github.com/rust-lang/compiler-builtins), the
+/// soft-float routines the compiler calls on targets without hardware
+/// floating point, linked into every Rust program.
+///
+/// The routines are called by symbol so the compiler cannot turn them back
+/// into FPU instructions. They round to nearest, ties to even, and report no
+/// exceptions, and there is no square root, fma or remainder among them.
+/// The `f16` routines are left out, as their ABI still differs between
+/// targets.
+pub struct CompilerBuiltins;
+
+impl Backend for CompilerBuiltins {
+    const NAME: &'static str = "compiler_builtins";
+}
+
+extern "C" {
+    fn __addsf3(a: f32, b: f32) -> f32;
+    fn __subsf3(a: f32, b: f32) -> f32;
+    fn __mulsf3(a: f32, b: f32) -> f32;
+    fn __divsf3(a: f32, b: f32) -> f32;
+    fn __adddf3(a: f64, b: f64) -> f64;
+    fn __subdf3(a: f64, b: f64) -> f64;
+    fn __muldf3(a: f64, b: f64) -> f64;
+    fn __divdf3(a: f64, b: f64) -> f64;
+    fn __addtf3(a: f128, b: f128) -> f128;
+    fn __subtf3(a: f128, b: f128) -> f128;
+    fn __multf3(a: f128, b: f128) -> f128;
+    fn __divtf3(a: f128, b: f128) -> f128;
+    fn __extendsfdf2(a: f32) -> f64;
+    fn __extendsftf2(a: f32) -> f128;
+    fn __extenddftf2(a: f64) -> f128;
+    fn __truncdfsf2(a: f64) -> f32;
+    fn __trunctfsf2(a: f128) -> f32;
+    fn __trunctfdf2(a: f128) -> f64;
+    fn __eqsf2(a: f32, b: f32) -> i32;
+    fn __ltsf2(a: f32, b: f32) -> i32;
+    fn __lesf2(a: f32, b: f32) -> i32;
+    fn __unordsf2(a: f32, b: f32) -> i32;
+    fn __eqdf2(a: f64, b: f64) -> i32;
+    fn __ltdf2(a: f64, b: f64) -> i32;
+    fn __ledf2(a: f64, b: f64) -> i32;
+    fn __unorddf2(a: f64, b: f64) -> i32;
+    fn __eqtf2(a: f128, b: f128) -> i32;
+    fn __lttf2(a: f128, b: f128) -> i32;
+    fn __letf2(a: f128, b: f128) -> i32;
+    fn __unordtf2(a: f128, b: f128) -> i32;
+}
+
+macro_rules! impl_backend {
+    ($format:ty, $value:ident, $bits:ty, $add:ident, $sub:ident, $mul:ident, $div:ident) => {
+        impl SoftFloatBackend<$format> for CompilerBuiltins {
+            type Value = $value;
+
+            const ROUNDING_MODES: &'static [RoundingMode] = &[RoundingMode::TiesToEven];
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn from_bits(bits: $bits) -> $value {
+                $value::from_bits(bits)
+            }
+
+            fn to_bits(value: $value) -> $bits {
+                value.to_bits()
+            }
+
+            fn add(a: $value, b: $value, _env: &mut FpEnv) -> $value {
+                unsafe { $add(a, b) }
+            }
+
+            fn sub(a: $value, b: $value, _env: &mut FpEnv) -> $value {
+                unsafe { $sub(a, b) }
+            }
+
+            fn mul(a: $value, b: $value, _env: &mut FpEnv) -> $value {
+                unsafe { $mul(a, b) }
+            }
+
+            fn div(a: $value, b: $value, _env: &mut FpEnv) -> $value {
+                unsafe { $div(a, b) }
+            }
+        }
+    };
+}
+
+impl_backend!(Binary32, f32, u32, __addsf3, __subsf3, __mulsf3, __divsf3);
+impl_backend!(Binary64, f64, u64, __adddf3, __subdf3, __muldf3, __divdf3);
+impl_backend!(Binary128, f128, u128, __addtf3, __subtf3, __multf3, __divtf3);
+
+macro_rules! impl_convert {
+    ($($from:ty: $from_value:ident => $to:ty, $convert:ident;)*) => {
+        $(
+            impl Convert<$from, $to> for CompilerBuiltins {
+                const ROUNDING_MODES: &'static [RoundingMode] = &[RoundingMode::TiesToEven];
+                const EXCEPTION_FLAGS: bool = false;
+
+                fn convert(bits: <$from as Format>::Bits, _env: &mut FpEnv) -> <$to as Format>::Bits {
+                    unsafe { $convert($from_value::from_bits(bits)) }.to_bits()
+                }
+            }
+        )*
+    };
+}
+
+impl_convert! {
+    Binary32: f32 => Binary64, __extendsfdf2;
+    Binary32: f32 => Binary128, __extendsftf2;
+    Binary64: f64 => Binary32, __truncdfsf2;
+    Binary64: f64 => Binary128, __extenddftf2;
+    Binary128: f128 => Binary32, __trunctfsf2;
+    Binary128: f128 => Binary64, __trunctfdf2;
+}
+
+/// The comparison routines return an integer compared against zero, with
+/// unordered operands giving the answer that makes the predicate false. They
+/// raise no flags, so quiet and signaling comparisons are the same.
+macro_rules! impl_compare {
+    ($($format:ty: $value:ident, $eq:ident, $lt:ident, $le:ident, $unord:ident;)*) => {
+        $(
+            impl Compare<$format> for CompilerBuiltins {
+                const EXCEPTION_FLAGS: bool = false;
+
+                fn compare_quiet(
+                    a: <$format as Format>::Bits,
+                    b: <$format as Format>::Bits,
+                    _env: &mut FpEnv,
+                ) -> Option<Ordering> {
+                    let (a, b) = ($value::from_bits(a), $value::from_bits(b));
+                    if unsafe { $unord(a, b) } != 0 {
+                        None
+                    } else {
+                        Some(unsafe { $le(a, b) }.cmp(&0))
+                    }
+                }
+
+                fn compare_signaling(
+                    a: <$format as Format>::Bits,
+                    b: <$format as Format>::Bits,
+                    env: &mut FpEnv,
+                ) -> Option<Ordering> {
+                    <Self as Compare<$format>>::compare_quiet(a, b, env)
+                }
+
+                fn eq(a: <$format as Format>::Bits, b: <$format as Format>::Bits, _env: &mut FpEnv) -> bool {
+                    unsafe { $eq($value::from_bits(a), $value::from_bits(b)) == 0 }
+                }
+
+                fn eq_signaling(
+                    a: <$format as Format>::Bits,
+                    b: <$format as Format>::Bits,
+                    env: &mut FpEnv,
+                ) -> bool {
+                    <Self as Compare<$format>>::eq(a, b, env)
+                }
+
+                fn lt(a: <$format as Format>::Bits, b: <$format as Format>::Bits, _env: &mut FpEnv) -> bool {
+                    unsafe { $lt($value::from_bits(a), $value::from_bits(b)) < 0 }
+                }
+
+                fn lt_quiet(
+                    a: <$format as Format>::Bits,
+                    b: <$format as Format>::Bits,
+                    env: &mut FpEnv,
+                ) -> bool {
+                    <Self as Compare<$format>>::lt(a, b, env)
+                }
+
+                fn le(a: <$format as Format>::Bits, b: <$format as Format>::Bits, _env: &mut FpEnv) -> bool {
+                    unsafe { $le($value::from_bits(a), $value::from_bits(b)) <= 0 }
+                }
+
+                fn le_quiet(
+                    a: <$format as Format>::Bits,
+                    b: <$format as Format>::Bits,
+                    env: &mut FpEnv,
+                ) -> bool {
+                    <Self as Compare<$format>>::le(a, b, env)
+                }
+
+                fn unordered(
+                    a: <$format as Format>::Bits,
+                    b: <$format as Format>::Bits,
+                    _env: &mut FpEnv,
+                ) -> bool {
+                    unsafe { $unord($value::from_bits(a), $value::from_bits(b)) != 0 }
+                }
+            }
+        )*
+    };
+}
+
+impl_compare! {
+    Binary32: f32, __eqsf2, __ltsf2, __lesf2, __unordsf2;
+    Binary64: f64, __eqdf2, __ltdf2, __ledf2, __unorddf2;
+    Binary128: f128, __eqtf2, __lttf2, __letf2, __unordtf2;
+}
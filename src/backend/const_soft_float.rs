@@ -0,0 +1,70 @@
+use super::{Backend, Compare, SoftFloatBackend};
+use crate::env::{FpEnv, RoundingMode};
+use crate::format::{Binary32, Binary64};
+use const_soft_float::soft_f32::SoftF32;
+use const_soft_float::soft_f64::SoftF64;
+use std::cmp::Ordering;
+
+/// [const_soft_float](https://github.com/823984418/const_soft_float)
+///
+/// Only rounds to nearest, ties to even, so the environment is ignored and no
+/// exception flags are raised.
+pub struct ConstSoftFloat;
+
+impl Backend for ConstSoftFloat {
+    const NAME: &'static str = "const_soft_float";
+}
+
+macro_rules! impl_backend {
+    ($format:ty, $value:ident, $bits:ty) => {
+        impl SoftFloatBackend<$format> for ConstSoftFloat {
+            type Value = $value;
+
+            const ROUNDING_MODES: &'static [RoundingMode] = &[RoundingMode::TiesToEven];
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn from_bits(bits: $bits) -> $value {
+                $value::from_bits(bits)
+            }
+
+            fn to_bits(value: $value) -> $bits {
+                value.to_bits()
+            }
+
+            fn add(a: $value, b: $value, _env: &mut FpEnv) -> $value {
+                a.add(b)
+            }
+
+            fn sub(a: $value, b: $value, _env: &mut FpEnv) -> $value {
+                a.sub(b)
+            }
+
+            fn mul(a: $value, b: $value, _env: &mut FpEnv) -> $value {
+                a.mul(b)
+            }
+
+            fn div(a: $value, b: $value, _env: &mut FpEnv) -> $value {
+                a.div(b)
+            }
+
+            fn sqrt(a: $value, _env: &mut FpEnv) -> Option<$value> {
+                Some(a.sqrt())
+            }
+        }
+
+        impl Compare<$format> for ConstSoftFloat {
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn compare_quiet(a: $bits, b: $bits, _env: &mut FpEnv) -> Option<Ordering> {
+                $value::from_bits(a).cmp($value::from_bits(b))
+            }
+
+            fn compare_signaling(a: $bits, b: $bits, _env: &mut FpEnv) -> Option<Ordering> {
+                $value::from_bits(a).cmp($value::from_bits(b))
+            }
+        }
+    };
+}
+
+impl_backend!(Binary32, SoftF32, u32);
+impl_backend!(Binary64, SoftF64, u64);
@@ -0,0 +1,149 @@
+use super::{Backend, Compare, Convert, Decimal, MinMax, SoftFloatBackend};
+use crate::env::{FpEnv, RoundingMode};
+use crate::format::{BFloat16, Binary16, Binary32, Binary64};
+use half::{bf16, f16};
+use std::cmp::Ordering;
+
+/// [half](https://github.com/VoidStarKat/half-rs)
+///
+/// Arithmetic goes through `f32` and rounds back to nearest, ties to even, so
+/// the environment is ignored and no exception flags are raised.
+pub struct Half;
+
+impl Backend for Half {
+    const NAME: &'static str = "half";
+}
+
+macro_rules! impl_backend {
+    ($format:ty, $value:ident) => {
+        impl SoftFloatBackend<$format> for Half {
+            type Value = $value;
+
+            const ROUNDING_MODES: &'static [RoundingMode] = &[RoundingMode::TiesToEven];
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn from_bits(bits: u16) -> $value {
+                $value::from_bits(bits)
+            }
+
+            fn to_bits(value: $value) -> u16 {
+                value.to_bits()
+            }
+
+            fn add(a: $value, b: $value, _env: &mut FpEnv) -> $value {
+                a + b
+            }
+
+            fn sub(a: $value, b: $value, _env: &mut FpEnv) -> $value {
+                a - b
+            }
+
+            fn mul(a: $value, b: $value, _env: &mut FpEnv) -> $value {
+                a * b
+            }
+
+            fn div(a: $value, b: $value, _env: &mut FpEnv) -> $value {
+                a / b
+            }
+
+            /// half has no square root of its own; like its arithmetic
+            /// operators this goes through `f32`, which is wide enough for
+            /// the result to be correctly rounded.
+            fn sqrt(a: $value, _env: &mut FpEnv) -> Option<$value> {
+                Some($value::from_f32(a.to_f32().sqrt()))
+            }
+
+            /// Also through `f32`, where the integral value is exact.
+            fn round_to_integral(a: $value, _env: &mut FpEnv) -> Option<$value> {
+                Some($value::from_f32(a.to_f32().round_ties_even()))
+            }
+        }
+
+        impl Convert<Binary32, $format> for Half {
+            const ROUNDING_MODES: &'static [RoundingMode] = &[RoundingMode::TiesToEven];
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn convert(bits: u32, _env: &mut FpEnv) -> u16 {
+                $value::from_f32(f32::from_bits(bits)).to_bits()
+            }
+        }
+
+        /// Exact, so any rounding mode will do.
+        impl Convert<$format, Binary32> for Half {
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn convert(bits: u16, _env: &mut FpEnv) -> u32 {
+                $value::from_bits(bits).to_f32().to_bits()
+            }
+        }
+
+        impl Convert<Binary64, $format> for Half {
+            const ROUNDING_MODES: &'static [RoundingMode] = &[RoundingMode::TiesToEven];
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn convert(bits: u64, _env: &mut FpEnv) -> u16 {
+                $value::from_f64(f64::from_bits(bits)).to_bits()
+            }
+        }
+
+        /// Exact, so any rounding mode will do.
+        impl Convert<$format, Binary64> for Half {
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn convert(bits: u16, _env: &mut FpEnv) -> u64 {
+                $value::from_bits(bits).to_f64().to_bits()
+            }
+        }
+
+        /// Text goes through `f32`: a literal is rounded to `f32` and then
+        /// to the format, which can round twice, and a value is printed as
+        /// the shortest `f32` literal, which parses back but may have more
+        /// digits than the format needs.
+        impl Decimal<$format> for Half {
+            const ROUNDING_MODES: &'static [RoundingMode] = &[RoundingMode::TiesToEven];
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn parse(text: &str, _env: &mut FpEnv) -> Option<u16> {
+                text.parse::<$value>().ok().map($value::to_bits)
+            }
+
+            fn format(bits: u16) -> String {
+                $value::from_bits(bits).to_string()
+            }
+        }
+
+        impl Compare<$format> for Half {
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn compare_quiet(a: u16, b: u16, _env: &mut FpEnv) -> Option<Ordering> {
+                $value::from_bits(a).partial_cmp(&$value::from_bits(b))
+            }
+
+            fn compare_signaling(a: u16, b: u16, _env: &mut FpEnv) -> Option<Ordering> {
+                $value::from_bits(a).partial_cmp(&$value::from_bits(b))
+            }
+        }
+
+        /// `min` and `max` ignore a NaN argument but return `self` whenever it
+        /// is a NaN, so a NaN `a` is replaced by `b` to make them `minNum` and
+        /// `maxNum`.
+        impl MinMax<$format> for Half {
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn min_num(a: u16, b: u16, _env: &mut FpEnv) -> Option<u16> {
+                let (a, b) = ($value::from_bits(a), $value::from_bits(b));
+                let d = if a.is_nan() { b } else { a.min(b) };
+                Some(d.to_bits())
+            }
+
+            fn max_num(a: u16, b: u16, _env: &mut FpEnv) -> Option<u16> {
+                let (a, b) = ($value::from_bits(a), $value::from_bits(b));
+                let d = if a.is_nan() { b } else { a.max(b) };
+                Some(d.to_bits())
+            }
+        }
+    };
+}
+
+impl_backend!(Binary16, f16);
+impl_backend!(BFloat16, bf16);
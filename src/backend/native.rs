@@ -0,0 +1,287 @@
+use super::{Backend, Compare, Convert, Decimal, FromInt, MinMax, SoftFloatBackend, ToInt};
+use crate::env::{FpEnv, RoundingMode};
+use crate::format::{Binary128, Binary16, Binary32, Binary64, Format};
+use std::cmp::Ordering;
+
+/// The primitive float types, as a baseline for the soft-float libraries.
+///
+/// `f32` and `f64` run on the FPU. Where the target has no instructions for
+/// `f16` or `f128`, as on x86-64 without AVX512-FP16, the compiler lowers them
+/// to compiler-builtins' soft-float routines (`f16` through `f32`), so those
+/// formats time that lowering instead. `sqrt` and `mul_add` call the target's
+/// libm. Rust has no way to change the rounding mode or read the status flags,
+/// and `%` truncates rather than implementing IEEE 754 `remainder`, which is
+/// left out.
+pub struct Native;
+
+impl Backend for Native {
+    const NAME: &'static str = "native";
+}
+
+macro_rules! impl_backend {
+    ($format:ty, $value:ident, $bits:ty) => {
+        impl SoftFloatBackend<$format> for Native {
+            type Value = $value;
+
+            const ROUNDING_MODES: &'static [RoundingMode] = &[RoundingMode::TiesToEven];
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn from_bits(bits: $bits) -> $value {
+                $value::from_bits(bits)
+            }
+
+            fn to_bits(value: $value) -> $bits {
+                value.to_bits()
+            }
+
+            fn add(a: $value, b: $value, _env: &mut FpEnv) -> $value {
+                a + b
+            }
+
+            fn sub(a: $value, b: $value, _env: &mut FpEnv) -> $value {
+                a - b
+            }
+
+            fn mul(a: $value, b: $value, _env: &mut FpEnv) -> $value {
+                a * b
+            }
+
+            fn div(a: $value, b: $value, _env: &mut FpEnv) -> $value {
+                a / b
+            }
+
+            fn sqrt(a: $value, _env: &mut FpEnv) -> Option<$value> {
+                Some(a.sqrt())
+            }
+
+            fn fma(a: $value, b: $value, c: $value, _env: &mut FpEnv) -> Option<$value> {
+                Some(a.mul_add(b, c))
+            }
+
+            fn round_to_integral(a: $value, _env: &mut FpEnv) -> Option<$value> {
+                Some(a.round_ties_even())
+            }
+        }
+    };
+}
+
+impl_backend!(Binary16, f16, u16);
+impl_backend!(Binary32, f32, u32);
+impl_backend!(Binary64, f64, u64);
+impl_backend!(Binary128, f128, u128);
+
+macro_rules! impl_convert {
+    ($($from:ty: $from_value:ident => $to:ty: $to_value:ident,)*) => {
+        $(
+            impl Convert<$from, $to> for Native {
+                const ROUNDING_MODES: &'static [RoundingMode] = &[RoundingMode::TiesToEven];
+                const EXCEPTION_FLAGS: bool = false;
+
+                fn convert(bits: <$from as Format>::Bits, _env: &mut FpEnv) -> <$to as Format>::Bits {
+                    ($from_value::from_bits(bits) as $to_value).to_bits()
+                }
+            }
+        )*
+    };
+}
+
+impl_convert! {
+    Binary16: f16 => Binary32: f32,
+    Binary16: f16 => Binary64: f64,
+    Binary16: f16 => Binary128: f128,
+    Binary32: f32 => Binary16: f16,
+    Binary32: f32 => Binary64: f64,
+    Binary32: f32 => Binary128: f128,
+    Binary64: f64 => Binary16: f16,
+    Binary64: f64 => Binary32: f32,
+    Binary64: f64 => Binary128: f128,
+    Binary128: f128 => Binary16: f16,
+    Binary128: f128 => Binary32: f32,
+    Binary128: f128 => Binary64: f64,
+}
+
+/// `as` rounds integers to nearest, ties to even. The other way it truncates,
+/// so the value is first rounded to an integral one in the environment's
+/// mode; `as` then saturates out-of-range values and turns NaNs into zero.
+macro_rules! impl_int {
+    ($format:ty, $value:ident, $int:ty) => {
+        impl FromInt<$int, $format> for Native {
+            const ROUNDING_MODES: &'static [RoundingMode] = &[RoundingMode::TiesToEven];
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn from_int(value: $int, _env: &mut FpEnv) -> <$format as Format>::Bits {
+                (value as $value).to_bits()
+            }
+        }
+
+        impl ToInt<$format, $int> for Native {
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn to_int(bits: <$format as Format>::Bits, env: &mut FpEnv) -> Option<$int> {
+                let value = $value::from_bits(bits);
+                let value = match env.rounding_mode {
+                    RoundingMode::TiesToEven => value.round_ties_even(),
+                    RoundingMode::TowardZero => value.trunc(),
+                    RoundingMode::TowardPositive => value.ceil(),
+                    RoundingMode::TowardNegative => value.floor(),
+                    RoundingMode::TiesToAway => value.round(),
+                };
+                Some(value as $int)
+            }
+        }
+    };
+}
+
+macro_rules! impl_ints {
+    ($($format:ty: $value:ident,)*) => {
+        $(
+            impl_int!($format, $value, i32);
+            impl_int!($format, $value, u32);
+            impl_int!($format, $value, i64);
+            impl_int!($format, $value, u64);
+            impl_int!($format, $value, i128);
+            impl_int!($format, $value, u128);
+        )*
+    };
+}
+
+impl_ints! {
+    Binary16: f16,
+    Binary32: f32,
+    Binary64: f64,
+    Binary128: f128,
+}
+
+/// `str::parse` is correctly rounded and `Display` prints the shortest
+/// literal that parses back, for `f32` and `f64` only; neither reads
+/// hexadecimal literals.
+macro_rules! impl_decimal {
+    ($($format:ty: $value:ident,)*) => {
+        $(
+            impl Decimal<$format> for Native {
+                const ROUNDING_MODES: &'static [RoundingMode] = &[RoundingMode::TiesToEven];
+                const EXCEPTION_FLAGS: bool = false;
+                const SHORTEST: bool = true;
+
+                fn parse(text: &str, _env: &mut FpEnv) -> Option<<$format as Format>::Bits> {
+                    text.parse::<$value>().ok().map($value::to_bits)
+                }
+
+                fn format(bits: <$format as Format>::Bits) -> String {
+                    $value::from_bits(bits).to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_decimal! {
+    Binary32: f32,
+    Binary64: f64,
+}
+
+/// The comparison operators raise no flags, so quiet and signaling
+/// comparisons are the same.
+macro_rules! impl_compare {
+    ($($format:ty: $value:ident,)*) => {
+        $(
+            impl Compare<$format> for Native {
+                const EXCEPTION_FLAGS: bool = false;
+
+                fn compare_quiet(
+                    a: <$format as Format>::Bits,
+                    b: <$format as Format>::Bits,
+                    _env: &mut FpEnv,
+                ) -> Option<Ordering> {
+                    $value::from_bits(a).partial_cmp(&$value::from_bits(b))
+                }
+
+                fn compare_signaling(
+                    a: <$format as Format>::Bits,
+                    b: <$format as Format>::Bits,
+                    env: &mut FpEnv,
+                ) -> Option<Ordering> {
+                    <Self as Compare<$format>>::compare_quiet(a, b, env)
+                }
+
+                fn eq(a: <$format as Format>::Bits, b: <$format as Format>::Bits, _env: &mut FpEnv) -> bool {
+                    $value::from_bits(a) == $value::from_bits(b)
+                }
+
+                fn lt(a: <$format as Format>::Bits, b: <$format as Format>::Bits, _env: &mut FpEnv) -> bool {
+                    $value::from_bits(a) < $value::from_bits(b)
+                }
+
+                fn le(a: <$format as Format>::Bits, b: <$format as Format>::Bits, _env: &mut FpEnv) -> bool {
+                    $value::from_bits(a) <= $value::from_bits(b)
+                }
+
+                fn unordered(
+                    a: <$format as Format>::Bits,
+                    b: <$format as Format>::Bits,
+                    _env: &mut FpEnv,
+                ) -> bool {
+                    $value::from_bits(a).is_nan() || $value::from_bits(b).is_nan()
+                }
+            }
+        )*
+    };
+}
+
+impl_compare! {
+    Binary16: f16,
+    Binary32: f32,
+    Binary64: f64,
+    Binary128: f128,
+}
+
+/// `min` and `max` are `minNum` and `maxNum`, and `minimum` and `maximum`
+/// IEEE 754-2019's operations of the same name.
+macro_rules! impl_min_max {
+    ($($format:ty: $value:ident,)*) => {
+        $(
+            impl MinMax<$format> for Native {
+                const EXCEPTION_FLAGS: bool = false;
+
+                fn minimum(
+                    a: <$format as Format>::Bits,
+                    b: <$format as Format>::Bits,
+                    _env: &mut FpEnv,
+                ) -> Option<<$format as Format>::Bits> {
+                    Some($value::from_bits(a).minimum($value::from_bits(b)).to_bits())
+                }
+
+                fn maximum(
+                    a: <$format as Format>::Bits,
+                    b: <$format as Format>::Bits,
+                    _env: &mut FpEnv,
+                ) -> Option<<$format as Format>::Bits> {
+                    Some($value::from_bits(a).maximum($value::from_bits(b)).to_bits())
+                }
+
+                fn min_num(
+                    a: <$format as Format>::Bits,
+                    b: <$format as Format>::Bits,
+                    _env: &mut FpEnv,
+                ) -> Option<<$format as Format>::Bits> {
+                    Some($value::from_bits(a).min($value::from_bits(b)).to_bits())
+                }
+
+                fn max_num(
+                    a: <$format as Format>::Bits,
+                    b: <$format as Format>::Bits,
+                    _env: &mut FpEnv,
+                ) -> Option<<$format as Format>::Bits> {
+                    Some($value::from_bits(a).max($value::from_bits(b)).to_bits())
+                }
+            }
+        )*
+    };
+}
+
+impl_min_max! {
+    Binary16: f16,
+    Binary32: f32,
+    Binary64: f64,
+    Binary128: f128,
+}
@@ -0,0 +1,369 @@
+use super::{Backend, Compare, Convert, Decimal, FromInt, MinMax, SoftFloatBackend, ToInt};
+use crate::env::{FpEnv, RoundingMode};
+use crate::format::{BFloat16, Binary16, Binary32, Binary64, Format};
+use rug::float::Round;
+use rug::ops::{AddAssignRound, AssignRound, DivAssignRound, MulAssignRound, SubAssignRound};
+use rug::{Assign, Float};
+use std::cmp::Ordering;
+
+/// [rug](https://crates.io/crates/rug), i.e. MPFR at the precision of the
+/// target format.
+///
+/// MPFR's exponent range is far wider than any of the formats, so every
+/// operation finishes by moving its result onto the format's subnormal grid
+/// with `subnormalize`, which takes the direction of MPFR's first rounding
+/// into account so the result is only rounded once, and by clamping overflow
+/// with the same rounding. MPFR's own flags describe its exponent range
+/// rather than the format's, so none are raised.
+pub struct Rug;
+
+impl Backend for Rug {
+    const NAME: &'static str = "rug";
+}
+
+fn round(env: &FpEnv) -> Round {
+    match env.rounding_mode {
+        RoundingMode::TiesToEven => Round::Nearest,
+        RoundingMode::TowardZero => Round::Zero,
+        RoundingMode::TowardPositive => Round::Up,
+        RoundingMode::TowardNegative => Round::Down,
+        RoundingMode::TiesToAway => panic!("MPFR has no roundTiesToAway"),
+    }
+}
+
+/// MPFR rounding modes other than roundTiesToAway.
+const MPFR_ROUNDING_MODES: &[RoundingMode] = &[
+    RoundingMode::TiesToEven,
+    RoundingMode::TowardZero,
+    RoundingMode::TowardPositive,
+    RoundingMode::TowardNegative,
+];
+
+fn from_f16(bits: u16) -> Float {
+    Float::with_val(11, half::f16::from_bits(bits).to_f32())
+}
+
+fn to_f16(value: &Float) -> u16 {
+    half::f16::from_f32(value.to_f32()).to_bits()
+}
+
+/// Replaces a value whose MPFR exponent is past `exp_max` by the largest
+/// finite number `max` or infinity, as `round` directs, for formats without
+/// a native type to convert to.
+fn overflow(value: &mut Float, exp_max: i32, max: f32, round: Round) {
+    if value.is_normal() && value.get_exp().unwrap() > exp_max {
+        let negative = value.is_sign_negative();
+        let max = match round {
+            Round::Zero => max,
+            Round::Up if negative => max,
+            Round::Down if !negative => max,
+            _ => f32::INFINITY,
+        };
+        value.assign(if negative { -max } else { max });
+    }
+}
+
+/// Rounds once into binary16, whose smallest normal exponent is -13 in MPFR's
+/// convention and whose largest finite number is 65504.
+fn fit_f16(value: &mut Float, ordering: Ordering, round: Round) {
+    value.subnormalize_round(ordering, -13, round);
+    overflow(value, 16, 65504.0, round);
+}
+
+fn from_bf16(bits: u16) -> Float {
+    Float::with_val(8, half::bf16::from_bits(bits).to_f32())
+}
+
+fn to_bf16(value: &Float) -> u16 {
+    half::bf16::from_f32(value.to_f32()).to_bits()
+}
+
+/// Rounds once into bfloat16, which shares binary32's exponent range but
+/// stops short of its largest finite number.
+fn fit_bf16(value: &mut Float, ordering: Ordering, round: Round) {
+    value.subnormalize_round(ordering, -125, round);
+    overflow(value, 128, half::bf16::MAX.to_f32(), round);
+}
+
+fn from_f32(bits: u32) -> Float {
+    Float::with_val(24, f32::from_bits(bits))
+}
+
+fn to_f32(value: &Float) -> u32 {
+    value.to_f32().to_bits()
+}
+
+fn fit_f32(value: &mut Float, ordering: Ordering, round: Round) {
+    value.subnormalize_round(ordering, -125, round);
+    let x = value.to_f32_round(round);
+    value.assign(x);
+}
+
+fn from_f64(bits: u64) -> Float {
+    Float::with_val(53, f64::from_bits(bits))
+}
+
+fn to_f64(value: &Float) -> u64 {
+    value.to_f64().to_bits()
+}
+
+fn fit_f64(value: &mut Float, ordering: Ordering, round: Round) {
+    value.subnormalize_round(ordering, -1021, round);
+    let x = value.to_f64_round(round);
+    value.assign(x);
+}
+
+macro_rules! impl_backend {
+    ($format:ty, $bits:ty, $modes:expr, $from_bits:ident, $to_bits:ident, $fit:ident) => {
+        impl SoftFloatBackend<$format> for Rug {
+            type Value = Float;
+
+            const ROUNDING_MODES: &'static [RoundingMode] = $modes;
+
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn from_bits(bits: $bits) -> Float {
+                $from_bits(bits)
+            }
+
+            fn to_bits(value: Float) -> $bits {
+                $to_bits(&value)
+            }
+
+            fn add(mut a: Float, b: Float, env: &mut FpEnv) -> Float {
+                let round = round(env);
+                let ordering = a.add_assign_round(&b, round);
+                $fit(&mut a, ordering, round);
+                a
+            }
+
+            fn sub(mut a: Float, b: Float, env: &mut FpEnv) -> Float {
+                let round = round(env);
+                let ordering = a.sub_assign_round(&b, round);
+                $fit(&mut a, ordering, round);
+                a
+            }
+
+            fn mul(mut a: Float, b: Float, env: &mut FpEnv) -> Float {
+                let round = round(env);
+                let ordering = a.mul_assign_round(&b, round);
+                $fit(&mut a, ordering, round);
+                a
+            }
+
+            fn div(mut a: Float, b: Float, env: &mut FpEnv) -> Float {
+                let round = round(env);
+                let ordering = a.div_assign_round(&b, round);
+                $fit(&mut a, ordering, round);
+                a
+            }
+
+            fn sqrt(mut a: Float, env: &mut FpEnv) -> Option<Float> {
+                let round = round(env);
+                let ordering = a.sqrt_round(round);
+                $fit(&mut a, ordering, round);
+                Some(a)
+            }
+
+            fn fma(mut a: Float, b: Float, c: Float, env: &mut FpEnv) -> Option<Float> {
+                let round = round(env);
+                let ordering = a.mul_add_round(&b, &c, round);
+                $fit(&mut a, ordering, round);
+                Some(a)
+            }
+
+            fn rem(mut a: Float, b: Float, env: &mut FpEnv) -> Option<Float> {
+                let round = round(env);
+                let ordering = a.remainder_round(&b, round);
+                $fit(&mut a, ordering, round);
+                Some(a)
+            }
+
+            /// The integral value is exact, so it needs no fitting.
+            fn round_to_integral(a: Float, env: &mut FpEnv) -> Option<Float> {
+                Some(match env.rounding_mode {
+                    RoundingMode::TiesToEven => a.round_even(),
+                    RoundingMode::TowardZero => a.trunc(),
+                    RoundingMode::TowardPositive => a.ceil(),
+                    RoundingMode::TowardNegative => a.floor(),
+                    RoundingMode::TiesToAway => a.round(),
+                })
+            }
+        }
+
+        impl Compare<$format> for Rug {
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn compare_quiet(a: $bits, b: $bits, _env: &mut FpEnv) -> Option<Ordering> {
+                $from_bits(a).partial_cmp(&$from_bits(b))
+            }
+
+            fn compare_signaling(a: $bits, b: $bits, _env: &mut FpEnv) -> Option<Ordering> {
+                $from_bits(a).partial_cmp(&$from_bits(b))
+            }
+        }
+
+        /// MPFR's `min` and `max` are `minNum` and `maxNum`, ordering `-0`
+        /// below `+0`.
+        impl MinMax<$format> for Rug {
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn min_num(a: $bits, b: $bits, _env: &mut FpEnv) -> Option<$bits> {
+                Some($to_bits(&$from_bits(a).min(&$from_bits(b))))
+            }
+
+            fn max_num(a: $bits, b: $bits, _env: &mut FpEnv) -> Option<$bits> {
+                Some($to_bits(&$from_bits(a).max(&$from_bits(b))))
+            }
+        }
+    };
+}
+
+impl_backend!(
+    Binary16,
+    u16,
+    MPFR_ROUNDING_MODES,
+    from_f16,
+    to_f16,
+    fit_f16
+);
+impl_backend!(
+    Binary32,
+    u32,
+    MPFR_ROUNDING_MODES,
+    from_f32,
+    to_f32,
+    fit_f32
+);
+impl_backend!(
+    Binary64,
+    u64,
+    MPFR_ROUNDING_MODES,
+    from_f64,
+    to_f64,
+    fit_f64
+);
+impl_backend!(
+    BFloat16,
+    u16,
+    MPFR_ROUNDING_MODES,
+    from_bf16,
+    to_bf16,
+    fit_bf16
+);
+
+macro_rules! impl_convert {
+    ($($from:ty: $from_bits:ident => $to:ty: $precision:expr, $to_bits:ident, $fit:ident;)*) => {
+        $(
+            impl Convert<$from, $to> for Rug {
+                const ROUNDING_MODES: &'static [RoundingMode] = MPFR_ROUNDING_MODES;
+                const EXCEPTION_FLAGS: bool = false;
+
+                fn convert(bits: <$from as Format>::Bits, env: &mut FpEnv) -> <$to as Format>::Bits {
+                    let round = round(env);
+                    let mut value = Float::new($precision);
+                    let ordering = value.assign_round($from_bits(bits), round);
+                    $fit(&mut value, ordering, round);
+                    $to_bits(&value)
+                }
+            }
+        )*
+    };
+}
+
+impl_convert! {
+    Binary16: from_f16 => Binary32: 24, to_f32, fit_f32;
+    Binary16: from_f16 => Binary64: 53, to_f64, fit_f64;
+    Binary32: from_f32 => Binary16: 11, to_f16, fit_f16;
+    Binary32: from_f32 => Binary64: 53, to_f64, fit_f64;
+    Binary64: from_f64 => Binary16: 11, to_f16, fit_f16;
+    Binary64: from_f64 => Binary32: 24, to_f32, fit_f32;
+    Binary32: from_f32 => BFloat16: 8, to_bf16, fit_bf16;
+    BFloat16: from_bf16 => Binary32: 24, to_f32, fit_f32;
+}
+
+/// Integers convert exactly and are rounded once by `fit`. Results out of
+/// the target type's range, and NaNs, give `None`.
+macro_rules! impl_int {
+    ($format:ty, $precision:expr, $from_bits:ident, $to_bits:ident, $fit:ident, $int:ty, $to_int:ident) => {
+        impl FromInt<$int, $format> for Rug {
+            const ROUNDING_MODES: &'static [RoundingMode] = MPFR_ROUNDING_MODES;
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn from_int(value: $int, env: &mut FpEnv) -> <$format as Format>::Bits {
+                let round = round(env);
+                let (mut value, ordering) = Float::with_val_round($precision, value, round);
+                $fit(&mut value, ordering, round);
+                $to_bits(&value)
+            }
+        }
+
+        impl ToInt<$format, $int> for Rug {
+            const ROUNDING_MODES: &'static [RoundingMode] = MPFR_ROUNDING_MODES;
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn to_int(bits: <$format as Format>::Bits, env: &mut FpEnv) -> Option<$int> {
+                let (integer, _) = $from_bits(bits).to_integer_round(round(env))?;
+                integer.$to_int()
+            }
+        }
+    };
+}
+
+macro_rules! impl_ints {
+    ($($format:ty: $precision:expr, $from_bits:ident, $to_bits:ident, $fit:ident;)*) => {
+        $(
+            impl_int!($format, $precision, $from_bits, $to_bits, $fit, i32, to_i32);
+            impl_int!($format, $precision, $from_bits, $to_bits, $fit, u32, to_u32);
+            impl_int!($format, $precision, $from_bits, $to_bits, $fit, i64, to_i64);
+            impl_int!($format, $precision, $from_bits, $to_bits, $fit, u64, to_u64);
+            impl_int!($format, $precision, $from_bits, $to_bits, $fit, i128, to_i128);
+            impl_int!($format, $precision, $from_bits, $to_bits, $fit, u128, to_u128);
+        )*
+    };
+}
+
+impl_ints! {
+    Binary16: 11, from_f16, to_f16, fit_f16;
+    Binary32: 24, from_f32, to_f32, fit_f32;
+    Binary64: 53, from_f64, to_f64, fit_f64;
+}
+
+/// MPFR reads decimal literals, and hexadecimal ones with a binary `p`
+/// exponent once the `0x` prefix is dropped, rounding them once before `fit`
+/// does the format's range. `to_string_radix` prints as many digits as the
+/// precision needs to tell values apart, so it is not the shortest literal.
+macro_rules! impl_decimal {
+    ($($format:ty: $precision:expr, $from_bits:ident, $to_bits:ident, $fit:ident;)*) => {
+        $(
+            impl Decimal<$format> for Rug {
+                const ROUNDING_MODES: &'static [RoundingMode] = MPFR_ROUNDING_MODES;
+                const EXCEPTION_FLAGS: bool = false;
+
+                fn parse(text: &str, env: &mut FpEnv) -> Option<<$format as Format>::Bits> {
+                    let round = round(env);
+                    let parsed = if text.contains("0x") {
+                        Float::parse_radix(text.replacen("0x", "", 1), 16)
+                    } else {
+                        Float::parse(text)
+                    };
+                    let (mut value, ordering) =
+                        Float::with_val_round($precision, parsed.ok()?, round);
+                    $fit(&mut value, ordering, round);
+                    Some($to_bits(&value))
+                }
+
+                fn format(bits: <$format as Format>::Bits) -> String {
+                    $from_bits(bits).to_string_radix(10, None)
+                }
+            }
+        )*
+    };
+}
+
+impl_decimal! {
+    Binary16: 11, from_f16, to_f16, fit_f16;
+    Binary32: 24, from_f32, to_f32, fit_f32;
+    Binary64: 53, from_f64, to_f64, fit_f64;
+    BFloat16: 8, from_bf16, to_bf16, fit_bf16;
+}
@@ -0,0 +1,282 @@
+use super::{Backend, Compare, Convert, Decimal, FromInt, MinMax, SoftFloatBackend, ToInt};
+use crate::env::{ExceptionFlags, FpEnv, RoundingMode};
+use crate::format::{
+    BFloat16, Binary128, Binary16, Binary32, Binary64, Float8E4M3FN, Float8E5M2, Format,
+    X87DoubleExtended,
+};
+use rustc_apfloat::ieee::{self, BFloat, Double, Half, Quad, Single};
+use rustc_apfloat::{Float, FloatConvert, Round, Status, StatusAnd};
+use std::cmp::Ordering;
+
+/// [rustc_apfloat](https://github.com/rust-lang/rustc_apfloat)
+///
+/// Like LLVM's APFloat it is ported from, it rounds results that would be
+/// infinite to NaN in formats without infinities.
+pub struct RustcApfloat;
+
+impl Backend for RustcApfloat {
+    const NAME: &'static str = "rustc_apfloat";
+}
+
+fn round(env: &FpEnv) -> Round {
+    match env.rounding_mode {
+        RoundingMode::TiesToEven => Round::NearestTiesToEven,
+        RoundingMode::TowardZero => Round::TowardZero,
+        RoundingMode::TowardPositive => Round::TowardPositive,
+        RoundingMode::TowardNegative => Round::TowardNegative,
+        RoundingMode::TiesToAway => Round::NearestTiesToAway,
+    }
+}
+
+/// Raises the status an operation returned and unwraps its value.
+fn unpack<T>(env: &mut FpEnv, result: StatusAnd<T>) -> T {
+    for &(status, flag) in &[
+        (Status::INVALID_OP, ExceptionFlags::INVALID),
+        (Status::DIV_BY_ZERO, ExceptionFlags::DIVIDE_BY_ZERO),
+        (Status::OVERFLOW, ExceptionFlags::OVERFLOW),
+        (Status::UNDERFLOW, ExceptionFlags::UNDERFLOW),
+        (Status::INEXACT, ExceptionFlags::INEXACT),
+    ] {
+        if result.status.contains(status) {
+            env.raise(flag);
+        }
+    }
+    result.value
+}
+
+macro_rules! impl_backend {
+    ($format:ty, $value:ty) => {
+        impl SoftFloatBackend<$format> for RustcApfloat {
+            type Value = $value;
+
+            fn from_bits(bits: <$format as Format>::Bits) -> $value {
+                <$value>::from_bits(<$format>::widen(bits))
+            }
+
+            fn to_bits(value: $value) -> <$format as Format>::Bits {
+                <$format>::narrow(value.to_bits())
+            }
+
+            fn add(a: $value, b: $value, env: &mut FpEnv) -> $value {
+                let round = round(env);
+                unpack(env, a.add_r(b, round))
+            }
+
+            fn sub(a: $value, b: $value, env: &mut FpEnv) -> $value {
+                let round = round(env);
+                unpack(env, a.sub_r(b, round))
+            }
+
+            fn mul(a: $value, b: $value, env: &mut FpEnv) -> $value {
+                let round = round(env);
+                unpack(env, a.mul_r(b, round))
+            }
+
+            fn div(a: $value, b: $value, env: &mut FpEnv) -> $value {
+                let round = round(env);
+                unpack(env, a.div_r(b, round))
+            }
+
+            fn fma(a: $value, b: $value, c: $value, env: &mut FpEnv) -> Option<$value> {
+                let round = round(env);
+                Some(unpack(env, a.mul_add_r(b, c, round)))
+            }
+
+            /// The remainder is exact, so there is no rounding mode to pass.
+            fn rem(a: $value, b: $value, env: &mut FpEnv) -> Option<$value> {
+                Some(unpack(env, a.ieee_rem(b)))
+            }
+
+            /// rustc_apfloat raises inexact like `roundToIntegralExact`,
+            /// which is dropped.
+            fn round_to_integral(a: $value, env: &mut FpEnv) -> Option<$value> {
+                let mut result = a.round_to_integral(round(env));
+                result.status.remove(Status::INEXACT);
+                Some(unpack(env, result))
+            }
+        }
+
+        /// `partial_cmp` returns no status, so neither comparison signals.
+        impl Compare<$format> for RustcApfloat {
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn compare_quiet(
+                a: <$format as Format>::Bits,
+                b: <$format as Format>::Bits,
+                _env: &mut FpEnv,
+            ) -> Option<Ordering> {
+                let a = <$value>::from_bits(<$format>::widen(a));
+                a.partial_cmp(&<$value>::from_bits(<$format>::widen(b)))
+            }
+
+            fn compare_signaling(
+                a: <$format as Format>::Bits,
+                b: <$format as Format>::Bits,
+                _env: &mut FpEnv,
+            ) -> Option<Ordering> {
+                let a = <$value>::from_bits(<$format>::widen(a));
+                a.partial_cmp(&<$value>::from_bits(<$format>::widen(b)))
+            }
+        }
+
+        /// `min` and `max` are IEEE 754-2008's `minNum` and `maxNum`. None of
+        /// the four returns a status.
+        impl MinMax<$format> for RustcApfloat {
+            const EXCEPTION_FLAGS: bool = false;
+
+            fn minimum(
+                a: <$format as Format>::Bits,
+                b: <$format as Format>::Bits,
+                _env: &mut FpEnv,
+            ) -> Option<<$format as Format>::Bits> {
+                let a = <$value>::from_bits(<$format>::widen(a));
+                let b = <$value>::from_bits(<$format>::widen(b));
+                Some(<$format>::narrow(a.minimum(b).to_bits()))
+            }
+
+            fn maximum(
+                a: <$format as Format>::Bits,
+                b: <$format as Format>::Bits,
+                _env: &mut FpEnv,
+            ) -> Option<<$format as Format>::Bits> {
+                let a = <$value>::from_bits(<$format>::widen(a));
+                let b = <$value>::from_bits(<$format>::widen(b));
+                Some(<$format>::narrow(a.maximum(b).to_bits()))
+            }
+
+            fn min_num(
+                a: <$format as Format>::Bits,
+                b: <$format as Format>::Bits,
+                _env: &mut FpEnv,
+            ) -> Option<<$format as Format>::Bits> {
+                let a = <$value>::from_bits(<$format>::widen(a));
+                let b = <$value>::from_bits(<$format>::widen(b));
+                Some(<$format>::narrow(a.min(b).to_bits()))
+            }
+
+            fn max_num(
+                a: <$format as Format>::Bits,
+                b: <$format as Format>::Bits,
+                _env: &mut FpEnv,
+            ) -> Option<<$format as Format>::Bits> {
+                let a = <$value>::from_bits(<$format>::widen(a));
+                let b = <$value>::from_bits(<$format>::widen(b));
+                Some(<$format>::narrow(a.max(b).to_bits()))
+            }
+        }
+    };
+}
+
+impl_backend!(Binary16, Half);
+impl_backend!(Binary32, Single);
+impl_backend!(Binary64, Double);
+impl_backend!(Binary128, Quad);
+impl_backend!(BFloat16, BFloat);
+impl_backend!(Float8E5M2, ieee::Float8E5M2);
+impl_backend!(Float8E4M3FN, ieee::Float8E4M3FN);
+impl_backend!(X87DoubleExtended, ieee::X87DoubleExtended);
+
+macro_rules! impl_convert {
+    ($($from:ty: $from_value:ty => $to:ty: $to_value:ty,)*) => {
+        $(
+            impl Convert<$from, $to> for RustcApfloat {
+                fn convert(bits: <$from as Format>::Bits, env: &mut FpEnv) -> <$to as Format>::Bits {
+                    let round = round(env);
+                    let value = <$from_value>::from_bits(<$from>::widen(bits));
+                    let value: $to_value = unpack(env, value.convert_r(round, &mut false));
+                    <$to>::narrow(value.to_bits())
+                }
+            }
+        )*
+    };
+}
+
+impl_convert! {
+    Binary16: Half => Binary32: Single,
+    Binary16: Half => Binary64: Double,
+    Binary16: Half => Binary128: Quad,
+    Binary32: Single => Binary16: Half,
+    Binary32: Single => Binary64: Double,
+    Binary32: Single => Binary128: Quad,
+    Binary64: Double => Binary16: Half,
+    Binary64: Double => Binary32: Single,
+    Binary64: Double => Binary128: Quad,
+    Binary128: Quad => Binary16: Half,
+    Binary128: Quad => Binary32: Single,
+    Binary128: Quad => Binary64: Double,
+    Binary32: Single => BFloat16: BFloat,
+    BFloat16: BFloat => Binary32: Single,
+}
+
+/// Integers go through `i128` or `u128`, with the width of the target type
+/// bounding the result. Out-of-range operands saturate and NaNs give zero,
+/// raising invalid.
+macro_rules! impl_int {
+    ($format:ty, $value:ty, $int:ty, $from_int:ident, $to_int:ident) => {
+        impl FromInt<$int, $format> for RustcApfloat {
+            fn from_int(value: $int, env: &mut FpEnv) -> <$format as Format>::Bits {
+                let round = round(env);
+                let value: $value = unpack(env, <$value>::$from_int(value.into(), round));
+                <$format>::narrow(value.to_bits())
+            }
+        }
+
+        impl ToInt<$format, $int> for RustcApfloat {
+            fn to_int(bits: <$format as Format>::Bits, env: &mut FpEnv) -> Option<$int> {
+                let round = round(env);
+                let value = <$value>::from_bits(<$format>::widen(bits));
+                let width = <$int>::BITS as usize;
+                Some(unpack(env, value.$to_int(width, round, &mut false)) as $int)
+            }
+        }
+    };
+}
+
+macro_rules! impl_ints {
+    ($($format:ty: $value:ty,)*) => {
+        $(
+            impl_int!($format, $value, i32, from_i128_r, to_i128_r);
+            impl_int!($format, $value, u32, from_u128_r, to_u128_r);
+            impl_int!($format, $value, i64, from_i128_r, to_i128_r);
+            impl_int!($format, $value, u64, from_u128_r, to_u128_r);
+            impl_int!($format, $value, i128, from_i128_r, to_i128_r);
+            impl_int!($format, $value, u128, from_u128_r, to_u128_r);
+        )*
+    };
+}
+
+impl_ints! {
+    Binary16: Half,
+    Binary32: Single,
+    Binary64: Double,
+    Binary128: Quad,
+}
+
+/// `from_str_r` reads decimal and hexadecimal literals, rounding once.
+/// `Display` prints as many digits as the format's precision needs to tell
+/// values apart, dropping trailing zeros, so it is not the shortest literal.
+macro_rules! impl_decimal {
+    ($($format:ty: $value:ty,)*) => {
+        $(
+            impl Decimal<$format> for RustcApfloat {
+                fn parse(text: &str, env: &mut FpEnv) -> Option<<$format as Format>::Bits> {
+                    let round = round(env);
+                    let value: $value = unpack(env, <$value>::from_str_r(text, round).ok()?);
+                    Some(<$format>::narrow(value.to_bits()))
+                }
+
+                fn format(bits: <$format as Format>::Bits) -> String {
+                    <$value>::from_bits(<$format>::widen(bits)).to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_decimal! {
+    Binary16: Half,
+    Binary32: Single,
+    Binary64: Double,
+    Binary128: Quad,
+    BFloat16: BFloat,
+}
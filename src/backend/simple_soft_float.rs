@@ -0,0 +1,301 @@
+use super::{Backend, Compare, Convert, FromInt, SoftFloatBackend, ToInt};
+use crate::env::{ExceptionFlags, FpEnv, RoundingMode};
+use crate::format::{BFloat16, Binary128, Binary16, Binary32, Binary64, Float8E5M2, Format};
+use crate::platform::{NanPropagation, Platform, Tininess};
+use simple_soft_float::{
+    F128WithPlatformProperties, F128WithPlatformPropertiesTraits, F16WithPlatformProperties,
+    F16WithPlatformPropertiesTraits, F32WithPlatformProperties, F32WithPlatformPropertiesTraits,
+    F64WithPlatformProperties, F64WithPlatformPropertiesTraits, FPState, Float, FloatProperties,
+    FloatTraits, PlatformProperties, StatusFlags, TininessDetectionMode,
+};
+use std::cmp::Ordering;
+
+/// [simple-soft-float](https://crates.io/crates/simple-soft-float)
+pub struct SimpleSoftFloat;
+
+impl Backend for SimpleSoftFloat {
+    const NAME: &'static str = "simple_soft_float";
+}
+
+fn rounding_mode(env: &FpEnv) -> Option<simple_soft_float::RoundingMode> {
+    use simple_soft_float::RoundingMode as Mode;
+    Some(match env.rounding_mode {
+        RoundingMode::TiesToEven => Mode::TiesToEven,
+        RoundingMode::TowardZero => Mode::TowardZero,
+        RoundingMode::TowardPositive => Mode::TowardPositive,
+        RoundingMode::TowardNegative => Mode::TowardNegative,
+        RoundingMode::TiesToAway => Mode::TiesToAway,
+    })
+}
+
+/// The library's preset with the profile's NaN propagation. Values carry their
+/// properties, so operands are rebound to the environment's profile before
+/// each operation.
+fn properties(platform: Platform) -> PlatformProperties {
+    match platform.nan_propagation {
+        NanPropagation::Canonical => PlatformProperties::RISC_V,
+        NanPropagation::FirstOperand => PlatformProperties::X86_SSE,
+        NanPropagation::SignalingFirst => PlatformProperties::ARM,
+    }
+}
+
+/// Formats the library has no preset for, described by their field widths
+/// like the `*WithPlatformPropertiesTraits` presets. It cannot describe
+/// formats without infinities such as E4M3FN.
+macro_rules! custom_traits {
+    ($($traits:ident, $value:ident, $bits:ty, $exponent_bits:expr, $mantissa_bits:expr;)*) => {
+        $(
+            #[derive(Clone, Copy, Debug, PartialEq)]
+            pub struct $traits(PlatformProperties);
+
+            impl FloatTraits for $traits {
+                type Bits = $bits;
+
+                fn properties(&self) -> FloatProperties {
+                    FloatProperties::new_with_extended_flags(
+                        $exponent_bits,
+                        $mantissa_bits,
+                        true,
+                        true,
+                        self.0,
+                    )
+                }
+            }
+
+            type $value = Float<$traits>;
+        )*
+    };
+}
+
+custom_traits! {
+    BFloat16Traits, BF16, u16, 8, 7;
+    Float8E5M2Traits, E5M2, u8, 5, 2;
+}
+
+/// Rebinding of a value to a profile's properties.
+trait OnPlatform {
+    fn on(&self, env: &FpEnv) -> Self;
+}
+
+fn flags(status: StatusFlags) -> ExceptionFlags {
+    let mut flags = ExceptionFlags::NONE;
+    for &(status_flag, flag) in &[
+        (StatusFlags::INVALID_OPERATION, ExceptionFlags::INVALID),
+        (
+            StatusFlags::DIVISION_BY_ZERO,
+            ExceptionFlags::DIVIDE_BY_ZERO,
+        ),
+        (StatusFlags::OVERFLOW, ExceptionFlags::OVERFLOW),
+        (StatusFlags::UNDERFLOW, ExceptionFlags::UNDERFLOW),
+        (StatusFlags::INEXACT, ExceptionFlags::INEXACT),
+    ] {
+        if status.contains(status_flag) {
+            flags |= flag;
+        }
+    }
+    flags
+}
+
+/// Runs `op` with the environment's rounding mode and a fresh `FPState`
+/// detecting tininess as the profile does, then raises the status flags it
+/// collected.
+fn with_state<T>(
+    env: &mut FpEnv,
+    op: impl FnOnce(Option<simple_soft_float::RoundingMode>, Option<&mut FPState>) -> T,
+) -> T {
+    let mut state = FPState::default();
+    state.tininess_detection_mode = match env.platform.tininess {
+        Tininess::BeforeRounding => TininessDetectionMode::BeforeRounding,
+        Tininess::AfterRounding => TininessDetectionMode::AfterRounding,
+    };
+    let result = op(rounding_mode(env), Some(&mut state));
+    env.raise(flags(state.status_flags));
+    result
+}
+
+macro_rules! impl_backend {
+    ($format:ty, $value:ident, $traits:ident, $bits:ty) => {
+        impl OnPlatform for $value {
+            fn on(&self, env: &FpEnv) -> $value {
+                $value::from_bits_and_traits(*self.bits(), $traits(properties(env.platform)))
+            }
+        }
+
+        impl SoftFloatBackend<$format> for SimpleSoftFloat {
+            type Value = $value;
+
+            const PLATFORMS: &'static [Platform] = &Platform::ALL;
+
+            fn from_bits(bits: $bits) -> $value {
+                $value::from_bits_and_traits(bits, $traits(properties(Platform::default())))
+            }
+
+            fn to_bits(value: $value) -> $bits {
+                *value.bits()
+            }
+
+            fn add(a: $value, b: $value, env: &mut FpEnv) -> $value {
+                let (a, b) = (a.on(env), b.on(env));
+                with_state(env, |mode, state| a.add(&b, mode, state))
+            }
+
+            fn sub(a: $value, b: $value, env: &mut FpEnv) -> $value {
+                let (a, b) = (a.on(env), b.on(env));
+                with_state(env, |mode, state| a.sub(&b, mode, state))
+            }
+
+            fn mul(a: $value, b: $value, env: &mut FpEnv) -> $value {
+                let (a, b) = (a.on(env), b.on(env));
+                with_state(env, |mode, state| a.mul(&b, mode, state))
+            }
+
+            fn div(a: $value, b: $value, env: &mut FpEnv) -> $value {
+                let (a, b) = (a.on(env), b.on(env));
+                with_state(env, |mode, state| a.div(&b, mode, state))
+            }
+
+            fn sqrt(a: $value, env: &mut FpEnv) -> Option<$value> {
+                let a = a.on(env);
+                Some(with_state(env, |mode, state| a.sqrt(mode, state)))
+            }
+
+            fn fma(a: $value, b: $value, c: $value, env: &mut FpEnv) -> Option<$value> {
+                let (a, b, c) = (a.on(env), b.on(env), c.on(env));
+                Some(with_state(env, |mode, state| {
+                    a.fused_mul_add(&b, &c, mode, state)
+                }))
+            }
+
+            fn rem(a: $value, b: $value, env: &mut FpEnv) -> Option<$value> {
+                let (a, b) = (a.on(env), b.on(env));
+                Some(with_state(env, |mode, state| {
+                    a.ieee754_remainder(&b, mode, state)
+                }))
+            }
+
+            fn round_to_integral(a: $value, env: &mut FpEnv) -> Option<$value> {
+                let a = a.on(env);
+                Some(with_state(env, |mode, state| {
+                    a.round_to_integral(false, mode, state)
+                }))
+            }
+        }
+
+        impl Compare<$format> for SimpleSoftFloat {
+            fn compare_quiet(a: $bits, b: $bits, env: &mut FpEnv) -> Option<Ordering> {
+                let a = <SimpleSoftFloat as SoftFloatBackend<$format>>::from_bits(a).on(env);
+                let b = <SimpleSoftFloat as SoftFloatBackend<$format>>::from_bits(b).on(env);
+                with_state(env, |_, state| a.compare_quiet(&b, state))
+            }
+
+            fn compare_signaling(a: $bits, b: $bits, env: &mut FpEnv) -> Option<Ordering> {
+                let a = <SimpleSoftFloat as SoftFloatBackend<$format>>::from_bits(a).on(env);
+                let b = <SimpleSoftFloat as SoftFloatBackend<$format>>::from_bits(b).on(env);
+                with_state(env, |_, state| a.compare_signaling(&b, state))
+            }
+        }
+    };
+}
+
+impl_backend!(
+    Binary16,
+    F16WithPlatformProperties,
+    F16WithPlatformPropertiesTraits,
+    u16
+);
+impl_backend!(
+    Binary32,
+    F32WithPlatformProperties,
+    F32WithPlatformPropertiesTraits,
+    u32
+);
+impl_backend!(
+    Binary64,
+    F64WithPlatformProperties,
+    F64WithPlatformPropertiesTraits,
+    u64
+);
+impl_backend!(
+    Binary128,
+    F128WithPlatformProperties,
+    F128WithPlatformPropertiesTraits,
+    u128
+);
+impl_backend!(BFloat16, BF16, BFloat16Traits, u16);
+impl_backend!(Float8E5M2, E5M2, Float8E5M2Traits, u8);
+
+macro_rules! impl_convert {
+    ($($from:ty => $to:ty: $to_value:ident($to_traits:ident),)*) => {
+        $(
+            impl Convert<$from, $to> for SimpleSoftFloat {
+                fn convert(bits: <$from as Format>::Bits, env: &mut FpEnv) -> <$to as Format>::Bits {
+                    let value = <SimpleSoftFloat as SoftFloatBackend<$from>>::from_bits(bits).on(env);
+                    let traits = $to_traits(properties(env.platform));
+                    let value: $to_value = with_state(env, |mode, state| {
+                        value.convert_to_float_with_traits(traits, mode, state)
+                    });
+                    *value.bits()
+                }
+            }
+        )*
+    };
+}
+
+impl_convert! {
+    Binary16 => Binary32: F32WithPlatformProperties(F32WithPlatformPropertiesTraits),
+    Binary16 => Binary64: F64WithPlatformProperties(F64WithPlatformPropertiesTraits),
+    Binary16 => Binary128: F128WithPlatformProperties(F128WithPlatformPropertiesTraits),
+    Binary32 => Binary16: F16WithPlatformProperties(F16WithPlatformPropertiesTraits),
+    Binary32 => Binary64: F64WithPlatformProperties(F64WithPlatformPropertiesTraits),
+    Binary32 => Binary128: F128WithPlatformProperties(F128WithPlatformPropertiesTraits),
+    Binary64 => Binary16: F16WithPlatformProperties(F16WithPlatformPropertiesTraits),
+    Binary64 => Binary32: F32WithPlatformProperties(F32WithPlatformPropertiesTraits),
+    Binary64 => Binary128: F128WithPlatformProperties(F128WithPlatformPropertiesTraits),
+    Binary128 => Binary16: F16WithPlatformProperties(F16WithPlatformPropertiesTraits),
+    Binary128 => Binary32: F32WithPlatformProperties(F32WithPlatformPropertiesTraits),
+    Binary128 => Binary64: F64WithPlatformProperties(F64WithPlatformPropertiesTraits),
+    Binary32 => BFloat16: BF16(BFloat16Traits),
+    BFloat16 => Binary32: F32WithPlatformProperties(F32WithPlatformPropertiesTraits),
+}
+
+/// Out-of-range operands and NaNs return no integer and raise invalid.
+macro_rules! impl_int {
+    ($format:ty, $value:ident, $traits:ident, $int:ty, $from_int:ident, $to_int:ident) => {
+        impl FromInt<$int, $format> for SimpleSoftFloat {
+            fn from_int(value: $int, env: &mut FpEnv) -> <$format as Format>::Bits {
+                let traits = $traits(properties(env.platform));
+                let value = with_state(env, |mode, state| {
+                    $value::$from_int(value, mode, state, traits)
+                });
+                *value.bits()
+            }
+        }
+
+        impl ToInt<$format, $int> for SimpleSoftFloat {
+            fn to_int(bits: <$format as Format>::Bits, env: &mut FpEnv) -> Option<$int> {
+                let value = <SimpleSoftFloat as SoftFloatBackend<$format>>::from_bits(bits).on(env);
+                with_state(env, |mode, state| value.$to_int(true, mode, state))
+            }
+        }
+    };
+}
+
+macro_rules! impl_ints {
+    ($($format:ty: $value:ident($traits:ident),)*) => {
+        $(
+            impl_int!($format, $value, $traits, i32, from_i32_with_traits, to_i32);
+            impl_int!($format, $value, $traits, u32, from_u32_with_traits, to_u32);
+            impl_int!($format, $value, $traits, i64, from_i64_with_traits, to_i64);
+            impl_int!($format, $value, $traits, u64, from_u64_with_traits, to_u64);
+            impl_int!($format, $value, $traits, i128, from_i128_with_traits, to_i128);
+            impl_int!($format, $value, $traits, u128, from_u128_with_traits, to_u128);
+        )*
+    };
+}
+
+impl_ints! {
+    Binary16: F16WithPlatformProperties(F16WithPlatformPropertiesTraits),
+    Binary32: F32WithPlatformProperties(F32WithPlatformPropertiesTraits),
+    Binary64: F64WithPlatformProperties(F64WithPlatformPropertiesTraits),
+    Binary128: F128WithPlatformProperties(F128WithPlatformPropertiesTraits),
+}
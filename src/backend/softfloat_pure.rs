@@ -0,0 +1,97 @@
+use super::softfloat_sys::{exception_flags, rounding_mode, tininess};
+use super::{Backend, SoftFloatBackend};
+use crate::env::FpEnv;
+use crate::format::{Binary32, Binary64};
+use softfloat_pure::softfloat::{
+    f32_add, f32_div, f32_mul, f32_mulAdd, f32_rem, f32_sqrt, f32_sub, f64_add, f64_div, f64_mul,
+    f64_mulAdd, f64_rem, f64_sqrt, f64_sub,
+};
+use softfloat_pure::{float32_t, float64_t};
+
+/// [softfloat-pure](https://github.com/HarryR/softfloat-pure)
+///
+/// Rounding mode and tininess detection are passed explicitly instead of
+/// living in globals, and exception flags are returned alongside the result,
+/// all using the same encoding as Berkeley SoftFloat. Its NaN propagation is
+/// fixed and matches none of the platform profiles, so only tininess
+/// detection follows the environment's profile.
+pub struct SoftfloatPure;
+
+impl Backend for SoftfloatPure {
+    const NAME: &'static str = "softfloat_pure";
+}
+
+/// Runs `op` with the environment's rounding mode and tininess detection,
+/// raising the flags it returns next to its result.
+fn with_env<T>(env: &mut FpEnv, op: impl FnOnce(u8, u8) -> (T, u8)) -> T {
+    let (result, flags) = op(
+        rounding_mode(env.rounding_mode),
+        tininess(env.platform.tininess),
+    );
+    env.raise(exception_flags(flags));
+    result
+}
+
+macro_rules! impl_backend {
+    (
+        $format:ty,
+        $value:ident,
+        $bits:ty,
+        $add:ident,
+        $sub:ident,
+        $mul:ident,
+        $div:ident,
+        $sqrt:ident,
+        $fma:ident,
+        $rem:ident
+    ) => {
+        impl SoftFloatBackend<$format> for SoftfloatPure {
+            type Value = $value;
+
+            fn from_bits(bits: $bits) -> $value {
+                $value { v: bits }
+            }
+
+            fn to_bits(value: $value) -> $bits {
+                value.v
+            }
+
+            fn add(a: $value, b: $value, env: &mut FpEnv) -> $value {
+                with_env(env, |mode, tininess| $add(a, b, mode, tininess))
+            }
+
+            fn sub(a: $value, b: $value, env: &mut FpEnv) -> $value {
+                with_env(env, |mode, tininess| $sub(a, b, mode, tininess))
+            }
+
+            fn mul(a: $value, b: $value, env: &mut FpEnv) -> $value {
+                with_env(env, |mode, tininess| $mul(a, b, mode, tininess))
+            }
+
+            fn div(a: $value, b: $value, env: &mut FpEnv) -> $value {
+                with_env(env, |mode, tininess| $div(a, b, mode, tininess))
+            }
+
+            fn sqrt(a: $value, env: &mut FpEnv) -> Option<$value> {
+                Some(with_env(env, |mode, tininess| $sqrt(a, mode, tininess)))
+            }
+
+            fn fma(a: $value, b: $value, c: $value, env: &mut FpEnv) -> Option<$value> {
+                Some(with_env(env, |mode, tininess| {
+                    $fma(a, b, c, mode, tininess)
+                }))
+            }
+
+            fn rem(a: $value, b: $value, env: &mut FpEnv) -> Option<$value> {
+                Some(with_env(env, |mode, tininess| $rem(a, b, mode, tininess)))
+            }
+        }
+    };
+}
+
+impl_backend!(
+    Binary32, float32_t, u32, f32_add, f32_sub, f32_mul, f32_div, f32_sqrt, f32_mulAdd, f32_rem
+);
+impl_backend!(
+    Binary64, float64_t, u64, f64_add, f64_sub, f64_mul, f64_div, f64_sqrt, f64_mulAdd, f64_rem
+);
@@ -0,0 +1,445 @@
+use super::{Backend, Compare, Convert, FromInt, SoftFloatBackend, ToInt};
+use crate::env::{ExceptionFlags, FpEnv, RoundingMode};
+use crate::format::{Binary128, Binary16, Binary32, Binary64, Format, X87DoubleExtended};
+use crate::platform::{Platform, Tininess};
+use softfloat_sys::*;
+use std::cmp::Ordering;
+
+/// [softfloat-sys](https://crates.io/crates/softfloat-sys)
+///
+/// SoftFloat keeps its rounding mode, tininess detection and exception flags
+/// in globals, which are loaded from and stored back to the environment
+/// around every operation. NaN propagation is fixed when SoftFloat is built:
+/// the default `riscv` feature builds the RISC-V specialization, matching the
+/// default `riscv` profile, and the `x86` feature, which takes precedence, the
+/// 8086-SSE one.
+pub struct SoftfloatSys;
+
+#[cfg(not(any(feature = "x86", feature = "riscv")))]
+compile_error!("enable the `riscv` or the `x86` feature to select a SoftFloat specialization");
+
+#[cfg(not(feature = "x86"))]
+const PLATFORMS: &[Platform] = &[Platform::RISC_V];
+#[cfg(feature = "x86")]
+const PLATFORMS: &[Platform] = &[Platform::X86];
+
+impl Backend for SoftfloatSys {
+    const NAME: &'static str = "softfloat_sys";
+}
+
+/// SoftFloat's encoding of `mode`, shared with softfloat-pure.
+pub(super) fn rounding_mode(mode: RoundingMode) -> u8 {
+    (match mode {
+        RoundingMode::TiesToEven => softfloat_round_near_even,
+        RoundingMode::TowardZero => softfloat_round_minMag,
+        RoundingMode::TowardPositive => softfloat_round_max,
+        RoundingMode::TowardNegative => softfloat_round_min,
+        RoundingMode::TiesToAway => softfloat_round_near_maxMag,
+    }) as u8
+}
+
+/// SoftFloat's encoding of `tininess`, shared with softfloat-pure.
+pub(super) fn tininess(tininess: Tininess) -> u8 {
+    (match tininess {
+        Tininess::BeforeRounding => softfloat_tininess_beforeRounding,
+        Tininess::AfterRounding => softfloat_tininess_afterRounding,
+    }) as u8
+}
+
+/// Normalizes SoftFloat's `softfloat_flag_*` bits, shared with softfloat-pure.
+pub(super) fn exception_flags(raw: u8) -> ExceptionFlags {
+    let mut flags = ExceptionFlags::NONE;
+    for &(raw_flag, flag) in &[
+        (softfloat_flag_invalid, ExceptionFlags::INVALID),
+        (softfloat_flag_infinite, ExceptionFlags::DIVIDE_BY_ZERO),
+        (softfloat_flag_overflow, ExceptionFlags::OVERFLOW),
+        (softfloat_flag_underflow, ExceptionFlags::UNDERFLOW),
+        (softfloat_flag_inexact, ExceptionFlags::INEXACT),
+    ] {
+        if raw & raw_flag as u8 != 0 {
+            flags |= flag;
+        }
+    }
+    flags
+}
+
+/// Runs `op` in `env`, raising the flags it sets.
+fn with_env<T>(env: &mut FpEnv, op: impl FnOnce() -> T) -> T {
+    unsafe {
+        softfloat_roundingMode = rounding_mode(env.rounding_mode);
+        softfloat_detectTininess = tininess(env.platform.tininess);
+        softfloat_exceptionFlags = 0;
+    }
+    let result = op();
+    env.raise(exception_flags(unsafe { softfloat_exceptionFlags }));
+    result
+}
+
+macro_rules! impl_backend {
+    (
+        $format:ty,
+        $value:ident,
+        $bits:ty,
+        $add:ident,
+        $sub:ident,
+        $mul:ident,
+        $div:ident,
+        $sqrt:ident,
+        $fma:ident,
+        $rem:ident,
+        $round:ident
+    ) => {
+        impl SoftFloatBackend<$format> for SoftfloatSys {
+            type Value = $value;
+
+            const PLATFORMS: &'static [Platform] = PLATFORMS;
+
+            fn from_bits(bits: $bits) -> $value {
+                $value { v: bits }
+            }
+
+            fn to_bits(value: $value) -> $bits {
+                value.v
+            }
+
+            fn add(a: $value, b: $value, env: &mut FpEnv) -> $value {
+                with_env(env, || unsafe { $add(a, b) })
+            }
+
+            fn sub(a: $value, b: $value, env: &mut FpEnv) -> $value {
+                with_env(env, || unsafe { $sub(a, b) })
+            }
+
+            fn mul(a: $value, b: $value, env: &mut FpEnv) -> $value {
+                with_env(env, || unsafe { $mul(a, b) })
+            }
+
+            fn div(a: $value, b: $value, env: &mut FpEnv) -> $value {
+                with_env(env, || unsafe { $div(a, b) })
+            }
+
+            fn sqrt(a: $value, env: &mut FpEnv) -> Option<$value> {
+                Some(with_env(env, || unsafe { $sqrt(a) }))
+            }
+
+            fn fma(a: $value, b: $value, c: $value, env: &mut FpEnv) -> Option<$value> {
+                Some(with_env(env, || unsafe { $fma(a, b, c) }))
+            }
+
+            fn rem(a: $value, b: $value, env: &mut FpEnv) -> Option<$value> {
+                Some(with_env(env, || unsafe { $rem(a, b) }))
+            }
+
+            fn round_to_integral(a: $value, env: &mut FpEnv) -> Option<$value> {
+                let mode = rounding_mode(env.rounding_mode);
+                Some(with_env(env, || unsafe { $round(a, mode, false) }))
+            }
+        }
+    };
+}
+
+impl_backend!(
+    Binary16,
+    float16_t,
+    u16,
+    f16_add,
+    f16_sub,
+    f16_mul,
+    f16_div,
+    f16_sqrt,
+    f16_mulAdd,
+    f16_rem,
+    f16_roundToInt
+);
+impl_backend!(
+    Binary32,
+    float32_t,
+    u32,
+    f32_add,
+    f32_sub,
+    f32_mul,
+    f32_div,
+    f32_sqrt,
+    f32_mulAdd,
+    f32_rem,
+    f32_roundToInt
+);
+impl_backend!(
+    Binary64,
+    float64_t,
+    u64,
+    f64_add,
+    f64_sub,
+    f64_mul,
+    f64_div,
+    f64_sqrt,
+    f64_mulAdd,
+    f64_rem,
+    f64_roundToInt
+);
+
+impl SoftFloatBackend<Binary128> for SoftfloatSys {
+    type Value = float128_t;
+
+    const PLATFORMS: &'static [Platform] = PLATFORMS;
+
+    fn from_bits(bits: u128) -> float128_t {
+        float128_t {
+            v: [bits as u64, (bits >> 64) as u64],
+        }
+    }
+
+    fn to_bits(value: float128_t) -> u128 {
+        value.v[0] as u128 | (value.v[1] as u128) << 64
+    }
+
+    fn add(a: float128_t, b: float128_t, env: &mut FpEnv) -> float128_t {
+        with_env(env, || unsafe { f128_add(a, b) })
+    }
+
+    fn sub(a: float128_t, b: float128_t, env: &mut FpEnv) -> float128_t {
+        with_env(env, || unsafe { f128_sub(a, b) })
+    }
+
+    fn mul(a: float128_t, b: float128_t, env: &mut FpEnv) -> float128_t {
+        with_env(env, || unsafe { f128_mul(a, b) })
+    }
+
+    fn div(a: float128_t, b: float128_t, env: &mut FpEnv) -> float128_t {
+        with_env(env, || unsafe { f128_div(a, b) })
+    }
+
+    fn sqrt(a: float128_t, env: &mut FpEnv) -> Option<float128_t> {
+        Some(with_env(env, || unsafe { f128_sqrt(a) }))
+    }
+
+    fn fma(a: float128_t, b: float128_t, c: float128_t, env: &mut FpEnv) -> Option<float128_t> {
+        Some(with_env(env, || unsafe { f128_mulAdd(a, b, c) }))
+    }
+
+    fn rem(a: float128_t, b: float128_t, env: &mut FpEnv) -> Option<float128_t> {
+        Some(with_env(env, || unsafe { f128_rem(a, b) }))
+    }
+
+    fn round_to_integral(a: float128_t, env: &mut FpEnv) -> Option<float128_t> {
+        let mode = rounding_mode(env.rounding_mode);
+        Some(with_env(env, || unsafe { f128_roundToInt(a, mode, false) }))
+    }
+}
+
+/// SoftFloat has no fused multiply-add for the x87 format. Results are
+/// rounded to the full 64-bit significand, `extF80_roundingPrecision`'s
+/// default of 80.
+impl SoftFloatBackend<X87DoubleExtended> for SoftfloatSys {
+    type Value = extFloat80_t;
+
+    const PLATFORMS: &'static [Platform] = PLATFORMS;
+
+    fn from_bits(bits: u128) -> extFloat80_t {
+        extFloat80_t {
+            signif: bits as u64,
+            signExp: (bits >> 64) as u16,
+        }
+    }
+
+    fn to_bits(value: extFloat80_t) -> u128 {
+        value.signif as u128 | (value.signExp as u128) << 64
+    }
+
+    fn add(a: extFloat80_t, b: extFloat80_t, env: &mut FpEnv) -> extFloat80_t {
+        with_env(env, || unsafe { extF80_add(a, b) })
+    }
+
+    fn sub(a: extFloat80_t, b: extFloat80_t, env: &mut FpEnv) -> extFloat80_t {
+        with_env(env, || unsafe { extF80_sub(a, b) })
+    }
+
+    fn mul(a: extFloat80_t, b: extFloat80_t, env: &mut FpEnv) -> extFloat80_t {
+        with_env(env, || unsafe { extF80_mul(a, b) })
+    }
+
+    fn div(a: extFloat80_t, b: extFloat80_t, env: &mut FpEnv) -> extFloat80_t {
+        with_env(env, || unsafe { extF80_div(a, b) })
+    }
+
+    fn sqrt(a: extFloat80_t, env: &mut FpEnv) -> Option<extFloat80_t> {
+        Some(with_env(env, || unsafe { extF80_sqrt(a) }))
+    }
+
+    fn rem(a: extFloat80_t, b: extFloat80_t, env: &mut FpEnv) -> Option<extFloat80_t> {
+        Some(with_env(env, || unsafe { extF80_rem(a, b) }))
+    }
+
+    fn round_to_integral(a: extFloat80_t, env: &mut FpEnv) -> Option<extFloat80_t> {
+        let mode = rounding_mode(env.rounding_mode);
+        Some(with_env(env, || unsafe {
+            extF80_roundToInt(a, mode, false)
+        }))
+    }
+}
+
+macro_rules! impl_convert {
+    ($($from:ty => $to:ty: $convert:ident,)*) => {
+        $(
+            impl Convert<$from, $to> for SoftfloatSys {
+                fn convert(bits: <$from as Format>::Bits, env: &mut FpEnv) -> <$to as Format>::Bits {
+                    let value = <SoftfloatSys as SoftFloatBackend<$from>>::from_bits(bits);
+                    let value = with_env(env, || unsafe { $convert(value) });
+                    <SoftfloatSys as SoftFloatBackend<$to>>::to_bits(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_convert! {
+    Binary16 => Binary32: f16_to_f32,
+    Binary16 => Binary64: f16_to_f64,
+    Binary16 => Binary128: f16_to_f128,
+    Binary32 => Binary16: f32_to_f16,
+    Binary32 => Binary64: f32_to_f64,
+    Binary32 => Binary128: f32_to_f128,
+    Binary64 => Binary16: f64_to_f16,
+    Binary64 => Binary32: f64_to_f32,
+    Binary64 => Binary128: f64_to_f128,
+    Binary128 => Binary16: f128_to_f16,
+    Binary128 => Binary32: f128_to_f32,
+    Binary128 => Binary64: f128_to_f64,
+}
+
+/// SoftFloat takes the rounding mode of float to integer conversions as an
+/// argument; `exact` makes them raise inexact. Out-of-range operands and NaNs
+/// raise invalid and return the specialization's choice: x86's integer
+/// indefinite, the most negative signed or largest unsigned integer, for
+/// 8086-SSE, and the nearest integer in range, NaNs taken as positive, for
+/// RISC-V.
+macro_rules! impl_int {
+    ($($format:ty, $int:ty: $from_int:ident, $to_int:ident;)*) => {
+        $(
+            impl FromInt<$int, $format> for SoftfloatSys {
+                fn from_int(value: $int, env: &mut FpEnv) -> <$format as Format>::Bits {
+                    let value = with_env(env, || unsafe { $from_int(value) });
+                    <SoftfloatSys as SoftFloatBackend<$format>>::to_bits(value)
+                }
+            }
+
+            impl ToInt<$format, $int> for SoftfloatSys {
+                fn to_int(bits: <$format as Format>::Bits, env: &mut FpEnv) -> Option<$int> {
+                    let value = <SoftfloatSys as SoftFloatBackend<$format>>::from_bits(bits);
+                    let mode = rounding_mode(env.rounding_mode);
+                    Some(with_env(env, || unsafe { $to_int(value, mode, true) }))
+                }
+            }
+        )*
+    };
+}
+
+impl_int! {
+    Binary16, i32: i32_to_f16, f16_to_i32;
+    Binary16, u32: ui32_to_f16, f16_to_ui32;
+    Binary16, i64: i64_to_f16, f16_to_i64;
+    Binary16, u64: ui64_to_f16, f16_to_ui64;
+    Binary32, i32: i32_to_f32, f32_to_i32;
+    Binary32, u32: ui32_to_f32, f32_to_ui32;
+    Binary32, i64: i64_to_f32, f32_to_i64;
+    Binary32, u64: ui64_to_f32, f32_to_ui64;
+    Binary64, i32: i32_to_f64, f64_to_i32;
+    Binary64, u32: ui32_to_f64, f64_to_ui32;
+    Binary64, i64: i64_to_f64, f64_to_i64;
+    Binary64, u64: ui64_to_f64, f64_to_ui64;
+    Binary128, i32: i32_to_f128, f128_to_i32;
+    Binary128, u32: ui32_to_f128, f128_to_ui32;
+    Binary128, i64: i64_to_f128, f128_to_i64;
+    Binary128, u64: ui64_to_f128, f128_to_ui64;
+}
+
+/// SoftFloat has the six predicates but no three-way comparison, which is
+/// made from them.
+macro_rules! impl_compare {
+    (@predicate $format:ty, $name:ident, $predicate:ident) => {
+        fn $name(
+            a: <$format as Format>::Bits,
+            b: <$format as Format>::Bits,
+            env: &mut FpEnv,
+        ) -> bool {
+            let a = <SoftfloatSys as SoftFloatBackend<$format>>::from_bits(a);
+            let b = <SoftfloatSys as SoftFloatBackend<$format>>::from_bits(b);
+            with_env(env, || unsafe { $predicate(a, b) })
+        }
+    };
+    (
+        $($format:ty:
+            $eq:ident,
+            $eq_signaling:ident,
+            $lt:ident,
+            $lt_quiet:ident,
+            $le:ident,
+            $le_quiet:ident;
+        )*
+    ) => {
+        $(
+            impl Compare<$format> for SoftfloatSys {
+                fn compare_quiet(
+                    a: <$format as Format>::Bits,
+                    b: <$format as Format>::Bits,
+                    env: &mut FpEnv,
+                ) -> Option<Ordering> {
+                    let a = <SoftfloatSys as SoftFloatBackend<$format>>::from_bits(a);
+                    let b = <SoftfloatSys as SoftFloatBackend<$format>>::from_bits(b);
+                    with_env(env, || unsafe {
+                        if $lt_quiet(a, b) {
+                            Some(Ordering::Less)
+                        } else if $eq(a, b) {
+                            Some(Ordering::Equal)
+                        } else if $lt_quiet(b, a) {
+                            Some(Ordering::Greater)
+                        } else {
+                            None
+                        }
+                    })
+                }
+
+                fn compare_signaling(
+                    a: <$format as Format>::Bits,
+                    b: <$format as Format>::Bits,
+                    env: &mut FpEnv,
+                ) -> Option<Ordering> {
+                    let a = <SoftfloatSys as SoftFloatBackend<$format>>::from_bits(a);
+                    let b = <SoftfloatSys as SoftFloatBackend<$format>>::from_bits(b);
+                    with_env(env, || unsafe {
+                        if $lt(a, b) {
+                            Some(Ordering::Less)
+                        } else if $eq_signaling(a, b) {
+                            Some(Ordering::Equal)
+                        } else if $lt(b, a) {
+                            Some(Ordering::Greater)
+                        } else {
+                            None
+                        }
+                    })
+                }
+
+                impl_compare!(@predicate $format, eq, $eq);
+                impl_compare!(@predicate $format, eq_signaling, $eq_signaling);
+                impl_compare!(@predicate $format, lt, $lt);
+                impl_compare!(@predicate $format, lt_quiet, $lt_quiet);
+                impl_compare!(@predicate $format, le, $le);
+                impl_compare!(@predicate $format, le_quiet, $le_quiet);
+            }
+        )*
+    };
+}
+
+impl_compare! {
+    Binary16: f16_eq, f16_eq_signaling, f16_lt, f16_lt_quiet, f16_le, f16_le_quiet;
+    Binary32: f32_eq, f32_eq_signaling, f32_lt, f32_lt_quiet, f32_le, f32_le_quiet;
+    Binary64: f64_eq, f64_eq_signaling, f64_lt, f64_lt_quiet, f64_le, f64_le_quiet;
+    Binary128: f128_eq, f128_eq_signaling, f128_lt, f128_lt_quiet, f128_le, f128_le_quiet;
+    X87DoubleExtended:
+        extF80_eq,
+        extF80_eq_signaling,
+        extF80_lt,
+        extF80_lt_quiet,
+        extF80_le,
+        extF80_le_quiet;
+}
@@ -0,0 +1,861 @@
+//! Generic benchmark bodies shared by every format and backend.
+
+use crate::backend::{
+    Compare, Convert, Decimal, DynBackend, FromInt, MinMax, Op, SoftFloatBackend, ToInt,
+};
+use crate::env::{ExceptionFlags, FpEnv, RoundingMode};
+use crate::format::{Binary128, Binary32, Binary64, Format, Int};
+use crate::platform::Platform;
+use crate::reference;
+use test::Bencher;
+
+pub mod corpus;
+pub mod stream;
+
+/// Environment for running `B` in `mode`, panicking if the backend cannot
+/// honour the mode rather than timing it in some other one.
+fn fp_env<F: Format, B: SoftFloatBackend<F>>(mode: RoundingMode) -> FpEnv {
+    assert!(
+        B::ROUNDING_MODES.contains(&mode),
+        "{} has no {} {} rounding",
+        B::NAME,
+        F::NAME,
+        mode.name()
+    );
+    FpEnv::new(mode)
+}
+
+/// Checks the result of the untimed first run of an operation: its bits and,
+/// for backends that report them, the exception flags it raised.
+fn check<F: Format, B: SoftFloatBackend<F>>(
+    bits: F::Bits,
+    env: &FpEnv,
+    expected: F::Bits,
+    flags: ExceptionFlags,
+) {
+    assert_eq!(bits, expected);
+    if B::EXCEPTION_FLAGS {
+        assert_eq!(env.flags, flags, "{} {} exception flags", B::NAME, F::NAME);
+    }
+}
+
+/// Benchmarks `op` on one operand pair, checking the result bits on every
+/// iteration and the exception flags once.
+pub fn binary<F, B>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    [a, b]: [F::Bits; 2],
+    expected: F::Bits,
+    flags: ExceptionFlags,
+    op: impl Fn(B::Value, B::Value, &mut FpEnv) -> B::Value,
+) where
+    F: Format,
+    B: SoftFloatBackend<F>,
+{
+    let mut env = fp_env::<F, B>(mode);
+    let d = B::to_bits(op(B::from_bits(a), B::from_bits(b), &mut env));
+    check::<F, B>(d, &env, expected, flags);
+    bencher.iter(|| {
+        let a = B::from_bits(test::black_box(a));
+        let b = B::from_bits(test::black_box(b));
+        let d = B::to_bits(op(a, b, &mut env));
+        assert_eq!(d, expected);
+        d
+    });
+}
+
+/// Benchmarks `op` on one operand, checking the result bits on every
+/// iteration and the exception flags once.
+pub fn unary<F, B>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    [a]: [F::Bits; 1],
+    expected: F::Bits,
+    flags: ExceptionFlags,
+    op: impl Fn(B::Value, &mut FpEnv) -> B::Value,
+) where
+    F: Format,
+    B: SoftFloatBackend<F>,
+{
+    let mut env = fp_env::<F, B>(mode);
+    let d = B::to_bits(op(B::from_bits(a), &mut env));
+    check::<F, B>(d, &env, expected, flags);
+    bencher.iter(|| {
+        let a = B::from_bits(test::black_box(a));
+        let d = B::to_bits(op(a, &mut env));
+        assert_eq!(d, expected);
+        d
+    });
+}
+
+/// Benchmarks `op` on one operand triple, checking the result bits on every
+/// iteration and the exception flags once.
+pub fn ternary<F, B>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    [a, b, c]: [F::Bits; 3],
+    expected: F::Bits,
+    flags: ExceptionFlags,
+    op: impl Fn(B::Value, B::Value, B::Value, &mut FpEnv) -> B::Value,
+) where
+    F: Format,
+    B: SoftFloatBackend<F>,
+{
+    let mut env = fp_env::<F, B>(mode);
+    let d = B::to_bits(op(
+        B::from_bits(a),
+        B::from_bits(b),
+        B::from_bits(c),
+        &mut env,
+    ));
+    check::<F, B>(d, &env, expected, flags);
+    bencher.iter(|| {
+        let a = B::from_bits(test::black_box(a));
+        let b = B::from_bits(test::black_box(b));
+        let c = B::from_bits(test::black_box(c));
+        let d = B::to_bits(op(a, b, c, &mut env));
+        assert_eq!(d, expected);
+        d
+    });
+}
+
+/// Benchmarks `op` on all of `pairs` per iteration, without checking
+/// results.
+pub fn binary_many<F, B>(
+    bencher: &mut Bencher,
+    pairs: &[(F::Bits, F::Bits)],
+    op: impl Fn(B::Value, B::Value, &mut FpEnv) -> B::Value,
+) where
+    F: Format,
+    B: SoftFloatBackend<F>,
+{
+    let mut env = FpEnv::default();
+    bencher.iter(|| {
+        for &(a, b) in pairs {
+            let a = B::from_bits(test::black_box(a));
+            let b = B::from_bits(test::black_box(b));
+            test::black_box(B::to_bits(op(a, b, &mut env)));
+        }
+    });
+}
+
+/// Benchmarks `op` on all of `operands` per iteration, without checking
+/// results.
+pub fn unary_many<F, B>(
+    bencher: &mut Bencher,
+    operands: &[F::Bits],
+    op: impl Fn(B::Value, &mut FpEnv) -> B::Value,
+) where
+    F: Format,
+    B: SoftFloatBackend<F>,
+{
+    let mut env = FpEnv::default();
+    bencher.iter(|| {
+        for &a in operands {
+            let a = B::from_bits(test::black_box(a));
+            test::black_box(B::to_bits(op(a, &mut env)));
+        }
+    });
+}
+
+/// A binary operation, its operands and its expected result.
+pub type Case<F> = (Op, [<F as Format>::Bits; 2], <F as Format>::Bits);
+
+/// Benchmarks a list of binary operations with known results in `mode`,
+/// checking every result once before timing them all.
+pub fn cases<F, B>(bencher: &mut Bencher, mode: RoundingMode, cases: &[Case<F>])
+where
+    F: Format,
+    B: SoftFloatBackend<F>,
+{
+    let backend = DynBackend::<F>::new::<B>();
+    let mut env = fp_env::<F, B>(mode);
+    for &(op, operands, expected) in cases {
+        let d = backend.apply(op, &operands, &mut env);
+        let d = d.unwrap_or_else(|| panic!("{} has no {} {}", B::NAME, F::NAME, op.name()));
+        assert_eq!(d, expected, "{} {} {:x?}", B::NAME, op.name(), operands);
+    }
+    bencher.iter(|| {
+        for &(op, operands, _) in cases {
+            test::black_box(backend.apply(op, &test::black_box(operands), &mut env));
+        }
+    });
+}
+
+/// Benchmarks the operations of `cases` on `platform` in the default rounding
+/// mode, first checking every result's bits and flags against the reference
+/// on the same platform. Panics if the backend does not model the platform.
+pub fn platform<F, B>(bencher: &mut Bencher, platform: Platform, cases: &[(Op, Vec<F::Bits>)])
+where
+    F: Format,
+    B: SoftFloatBackend<F>,
+{
+    assert!(
+        B::PLATFORMS.contains(&platform),
+        "{} does not model the {} platform",
+        B::NAME,
+        platform.name
+    );
+    let backend = DynBackend::<F>::new::<B>();
+    let env = FpEnv {
+        platform,
+        ..FpEnv::default()
+    };
+    for (op, operands) in cases {
+        let (mut actual, mut expected) = (env.clone(), env.clone());
+        let d = backend.apply(*op, operands, &mut actual);
+        let d = d.unwrap_or_else(|| panic!("{} has no {} {}", B::NAME, F::NAME, op.name()));
+        let e = reference::apply::<F>(*op, operands, &mut expected);
+        let case = format!("{} {} {:x?}", B::NAME, op.name(), operands);
+        assert_eq!(d, e, "{}", case);
+        if B::EXCEPTION_FLAGS {
+            assert_eq!(actual.flags, expected.flags, "{} exception flags", case);
+        }
+    }
+    let mut env = env;
+    bencher.iter(|| {
+        for (op, operands) in cases {
+            test::black_box(backend.apply(*op, test::black_box(operands), &mut env));
+        }
+    });
+}
+
+/// Benchmarks `op` on every operand pair of a format of at most 8 bits in
+/// roundTiesToEven, after checking the results of all pairs against the
+/// reference in every rounding mode the backend supports. NaN results match
+/// any NaN.
+pub fn exhaustive<F, B>(bencher: &mut Bencher, op: Op)
+where
+    F: Format,
+    B: SoftFloatBackend<F>,
+{
+    let width = 1 + F::EXPONENT_BITS + F::MANTISSA_BITS;
+    assert!(width <= 8, "{} has too many operand pairs", F::NAME);
+    let encodings = 1 << width;
+    let pairs: Vec<[F::Bits; 2]> = (0..encodings * encodings)
+        .map(|i| [F::narrow(i / encodings), F::narrow(i % encodings)])
+        .collect();
+    let backend = DynBackend::<F>::new::<B>();
+    for &mode in B::ROUNDING_MODES {
+        let mut mismatches = vec![];
+        for operands in &pairs {
+            let expected = reference::apply::<F>(op, operands, &mut FpEnv::new(mode));
+            let d = backend.apply(op, operands, &mut FpEnv::new(mode));
+            let d = d.unwrap_or_else(|| panic!("{} has no {} {}", B::NAME, F::NAME, op.name()));
+            if d != expected && !(F::is_nan(d) && F::is_nan(expected)) {
+                mismatches.push((*operands, d, expected));
+            }
+        }
+        assert!(
+            mismatches.is_empty(),
+            "{} {} {} {}: {} mismatching pairs, starting with {:x?} (operands, result, expected)",
+            B::NAME,
+            F::NAME,
+            op.name(),
+            mode.name(),
+            mismatches.len(),
+            &mismatches[..mismatches.len().min(8)]
+        );
+    }
+    let mut env = fp_env::<F, B>(RoundingMode::TiesToEven);
+    bencher.iter(|| {
+        for operands in &pairs {
+            test::black_box(backend.apply(op, test::black_box(operands), &mut env));
+        }
+    });
+}
+
+/// Benchmarks [`SoftFloatBackend::add`].
+pub fn add<F: Format, B: SoftFloatBackend<F>>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    a: F::Bits,
+    b: F::Bits,
+    expected: F::Bits,
+    flags: ExceptionFlags,
+) {
+    binary::<F, B>(bencher, mode, [a, b], expected, flags, B::add);
+}
+
+/// Benchmarks [`SoftFloatBackend::sub`].
+pub fn sub<F: Format, B: SoftFloatBackend<F>>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    a: F::Bits,
+    b: F::Bits,
+    expected: F::Bits,
+    flags: ExceptionFlags,
+) {
+    binary::<F, B>(bencher, mode, [a, b], expected, flags, B::sub);
+}
+
+/// Benchmarks [`SoftFloatBackend::mul`].
+pub fn mul<F: Format, B: SoftFloatBackend<F>>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    a: F::Bits,
+    b: F::Bits,
+    expected: F::Bits,
+    flags: ExceptionFlags,
+) {
+    binary::<F, B>(bencher, mode, [a, b], expected, flags, B::mul);
+}
+
+/// Benchmarks [`SoftFloatBackend::div`].
+pub fn div<F: Format, B: SoftFloatBackend<F>>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    a: F::Bits,
+    b: F::Bits,
+    expected: F::Bits,
+    flags: ExceptionFlags,
+) {
+    binary::<F, B>(bencher, mode, [a, b], expected, flags, B::div);
+}
+
+/// Benchmarks [`SoftFloatBackend::sqrt`].
+pub fn sqrt<F: Format, B: SoftFloatBackend<F>>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    a: F::Bits,
+    expected: F::Bits,
+    flags: ExceptionFlags,
+) {
+    unary::<F, B>(bencher, mode, [a], expected, flags, |a, env| {
+        B::sqrt(a, env).unwrap_or_else(|| panic!("{} has no {} sqrt", B::NAME, F::NAME))
+    });
+}
+
+/// Benchmarks [`SoftFloatBackend::fma`].
+///
+/// The operands must be chosen so that a multiply followed by an add rounds
+/// differently from the fused operation; this is checked before timing, so
+/// a backend emulating FMA with two roundings fails the bench.
+pub fn fma<F: Format, B: SoftFloatBackend<F>>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    a: F::Bits,
+    b: F::Bits,
+    c: F::Bits,
+    expected: F::Bits,
+    flags: ExceptionFlags,
+) {
+    let mut env = fp_env::<F, B>(mode);
+    let product = B::mul(B::from_bits(a), B::from_bits(b), &mut env);
+    let unfused = B::to_bits(B::add(product, B::from_bits(c), &mut env));
+    assert_ne!(
+        unfused, expected,
+        "operands do not distinguish fused from unfused multiply-add"
+    );
+    ternary::<F, B>(bencher, mode, [a, b, c], expected, flags, |a, b, c, env| {
+        B::fma(a, b, c, env).unwrap_or_else(|| panic!("{} has no {} fma", B::NAME, F::NAME))
+    });
+}
+
+/// Benchmarks [`Convert::convert`] from `F` to `T`, checking the result bits
+/// on every iteration and the exception flags once.
+pub fn convert<F, T, B>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    a: F::Bits,
+    expected: T::Bits,
+    flags: ExceptionFlags,
+) where
+    F: Format,
+    T: Format,
+    B: Convert<F, T>,
+{
+    assert!(
+        <B as Convert<F, T>>::ROUNDING_MODES.contains(&mode),
+        "{} has no {} to {} {} rounding",
+        B::NAME,
+        F::NAME,
+        T::NAME,
+        mode.name()
+    );
+    let mut env = FpEnv::new(mode);
+    let d = B::convert(a, &mut env);
+    assert_eq!(d, expected);
+    if <B as Convert<F, T>>::EXCEPTION_FLAGS {
+        assert_eq!(
+            env.flags,
+            flags,
+            "{} {} to {} exception flags",
+            B::NAME,
+            F::NAME,
+            T::NAME
+        );
+    }
+    bencher.iter(|| {
+        let d = B::convert(test::black_box(a), &mut env);
+        assert_eq!(d, expected);
+        d
+    });
+}
+
+/// Benchmarks [`FromInt::from_int`] from `I` to `F`, checking the result bits
+/// on every iteration and the exception flags once.
+pub fn from_int<F, I, B>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    a: I,
+    expected: F::Bits,
+    flags: ExceptionFlags,
+) where
+    F: Format,
+    I: Int,
+    B: FromInt<I, F>,
+{
+    assert!(
+        <B as FromInt<I, F>>::ROUNDING_MODES.contains(&mode),
+        "{} has no {} to {} {} rounding",
+        B::NAME,
+        I::NAME,
+        F::NAME,
+        mode.name()
+    );
+    let mut env = FpEnv::new(mode);
+    let d = B::from_int(a, &mut env);
+    assert_eq!(d, expected);
+    if <B as FromInt<I, F>>::EXCEPTION_FLAGS {
+        assert_eq!(
+            env.flags,
+            flags,
+            "{} {} to {} exception flags",
+            B::NAME,
+            I::NAME,
+            F::NAME
+        );
+    }
+    bencher.iter(|| {
+        let d = B::from_int(test::black_box(a), &mut env);
+        assert_eq!(d, expected);
+        d
+    });
+}
+
+/// Benchmarks [`ToInt::to_int`] from `F` to `I` on an operand in range,
+/// checking the result on every iteration and the exception flags once.
+pub fn to_int<F, I, B>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    a: F::Bits,
+    expected: I,
+    flags: ExceptionFlags,
+) where
+    F: Format,
+    I: Int,
+    B: ToInt<F, I>,
+{
+    assert!(
+        <B as ToInt<F, I>>::ROUNDING_MODES.contains(&mode),
+        "{} has no {} to {} {} rounding",
+        B::NAME,
+        F::NAME,
+        I::NAME,
+        mode.name()
+    );
+    let mut env = FpEnv::new(mode);
+    let d = B::to_int(a, &mut env);
+    assert_eq!(d, Some(expected));
+    if <B as ToInt<F, I>>::EXCEPTION_FLAGS {
+        assert_eq!(
+            env.flags,
+            flags,
+            "{} {} to {} exception flags",
+            B::NAME,
+            F::NAME,
+            I::NAME
+        );
+    }
+    bencher.iter(|| {
+        let d = B::to_int(test::black_box(a), &mut env);
+        assert_eq!(d, Some(expected));
+        d
+    });
+}
+
+/// Benchmarks [`Decimal::parse`] of `text`, checking the result bits on every
+/// iteration and the exception flags once.
+pub fn parse<F: Format, B: Decimal<F>>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    text: &str,
+    expected: F::Bits,
+    flags: ExceptionFlags,
+) {
+    assert!(
+        B::ROUNDING_MODES.contains(&mode),
+        "{} has no {} parse {} rounding",
+        B::NAME,
+        F::NAME,
+        mode.name()
+    );
+    let mut env = FpEnv::new(mode);
+    let d = B::parse(text, &mut env);
+    assert_eq!(
+        d,
+        Some(expected),
+        "{} {} parse of {:?}",
+        B::NAME,
+        F::NAME,
+        text
+    );
+    if B::EXCEPTION_FLAGS {
+        assert_eq!(
+            env.flags,
+            flags,
+            "{} {} parse exception flags",
+            B::NAME,
+            F::NAME
+        );
+    }
+    bencher.iter(|| {
+        let d = B::parse(test::black_box(text), &mut env);
+        assert_eq!(d, Some(expected));
+        d
+    });
+}
+
+/// The significant digits of a decimal literal, without its sign, point,
+/// exponent and leading or trailing zeros.
+fn significant_digits(text: &str) -> String {
+    let significand = text.split(['e', 'E']).next().unwrap();
+    let digits: String = significand.chars().filter(char::is_ascii_digit).collect();
+    digits.trim_matches('0').to_string()
+}
+
+/// Benchmarks [`Decimal::format`] of `a`, first checking that the text
+/// parses back to `a` and, for backends printing the shortest literal, that
+/// it has the digits of `shortest`. Formatting neither rounds nor raises
+/// flags, so the mode and flags are not used.
+pub fn format<F: Format, B: Decimal<F>>(
+    bencher: &mut Bencher,
+    _mode: RoundingMode,
+    a: F::Bits,
+    shortest: &str,
+    _flags: ExceptionFlags,
+) {
+    let text = B::format(a);
+    assert_eq!(
+        reference::parse::<F>(&text),
+        Some(a),
+        "{} {} printed {:?}",
+        B::NAME,
+        F::NAME,
+        text
+    );
+    if B::SHORTEST {
+        assert_eq!(
+            significant_digits(&text),
+            significant_digits(shortest),
+            "{} {} printed {:?}",
+            B::NAME,
+            F::NAME,
+            text
+        );
+    }
+    bencher.iter(|| B::format(test::black_box(a)));
+}
+
+/// Benchmarks the comparison `predicate` on one operand pair, checking the
+/// result on every iteration and, for backends that report them, the
+/// exception flags once. Comparisons do not round, so the mode is not used.
+fn compare<F: Format, B: Compare<F>>(
+    bencher: &mut Bencher,
+    name: &str,
+    predicate: fn(F::Bits, F::Bits, &mut FpEnv) -> bool,
+    a: F::Bits,
+    b: F::Bits,
+    expected: bool,
+    flags: ExceptionFlags,
+) {
+    let mut env = FpEnv::default();
+    let d = predicate(a, b, &mut env);
+    assert_eq!(d, expected, "{} {} {}", B::NAME, F::NAME, name);
+    if B::EXCEPTION_FLAGS {
+        assert_eq!(
+            env.flags,
+            flags,
+            "{} {} {} exception flags",
+            B::NAME,
+            F::NAME,
+            name
+        );
+    }
+    bencher.iter(|| {
+        let d = predicate(test::black_box(a), test::black_box(b), &mut env);
+        assert_eq!(d, expected);
+        d
+    });
+}
+
+/// Benchmarks [`Compare::eq`].
+pub fn eq<F: Format, B: Compare<F>>(
+    bencher: &mut Bencher,
+    _mode: RoundingMode,
+    a: F::Bits,
+    b: F::Bits,
+    expected: bool,
+    flags: ExceptionFlags,
+) {
+    compare::<F, B>(bencher, "eq", B::eq, a, b, expected, flags);
+}
+
+/// Benchmarks [`Compare::eq_signaling`], which signals on a quiet NaN.
+pub fn eq_signaling<F: Format, B: Compare<F>>(
+    bencher: &mut Bencher,
+    _mode: RoundingMode,
+    a: F::Bits,
+    b: F::Bits,
+    expected: bool,
+    flags: ExceptionFlags,
+) {
+    compare::<F, B>(
+        bencher,
+        "eq_signaling",
+        B::eq_signaling,
+        a,
+        b,
+        expected,
+        flags,
+    );
+}
+
+/// Benchmarks [`Compare::lt`].
+pub fn lt<F: Format, B: Compare<F>>(
+    bencher: &mut Bencher,
+    _mode: RoundingMode,
+    a: F::Bits,
+    b: F::Bits,
+    expected: bool,
+    flags: ExceptionFlags,
+) {
+    compare::<F, B>(bencher, "lt", B::lt, a, b, expected, flags);
+}
+
+/// Benchmarks [`Compare::lt_quiet`] of a quiet NaN, which does not signal.
+pub fn lt_quiet<F: Format, B: Compare<F>>(
+    bencher: &mut Bencher,
+    _mode: RoundingMode,
+    a: F::Bits,
+    b: F::Bits,
+    expected: bool,
+    flags: ExceptionFlags,
+) {
+    compare::<F, B>(bencher, "lt_quiet", B::lt_quiet, a, b, expected, flags);
+}
+
+/// Benchmarks [`Compare::le`].
+pub fn le<F: Format, B: Compare<F>>(
+    bencher: &mut Bencher,
+    _mode: RoundingMode,
+    a: F::Bits,
+    b: F::Bits,
+    expected: bool,
+    flags: ExceptionFlags,
+) {
+    compare::<F, B>(bencher, "le", B::le, a, b, expected, flags);
+}
+
+/// Benchmarks [`Compare::le_quiet`] of a signaling NaN, which signals even in a
+/// quiet comparison.
+pub fn le_quiet<F: Format, B: Compare<F>>(
+    bencher: &mut Bencher,
+    _mode: RoundingMode,
+    a: F::Bits,
+    b: F::Bits,
+    expected: bool,
+    flags: ExceptionFlags,
+) {
+    compare::<F, B>(bencher, "le_quiet", B::le_quiet, a, b, expected, flags);
+}
+
+/// Benchmarks [`Compare::unordered`].
+pub fn unordered<F: Format, B: Compare<F>>(
+    bencher: &mut Bencher,
+    _mode: RoundingMode,
+    a: F::Bits,
+    b: F::Bits,
+    expected: bool,
+    flags: ExceptionFlags,
+) {
+    compare::<F, B>(bencher, "unordered", B::unordered, a, b, expected, flags);
+}
+
+type MinMaxFn<F> =
+    fn(<F as Format>::Bits, <F as Format>::Bits, &mut FpEnv) -> Option<<F as Format>::Bits>;
+
+/// Benchmarks the [`MinMax`] operation `op` on one operand pair, checking the
+/// result bits on every iteration and, for backends that report them, the
+/// exception flags once. None of them round, so the mode is not used.
+fn min_max<F: Format, B: MinMax<F>>(
+    bencher: &mut Bencher,
+    name: &str,
+    op: MinMaxFn<F>,
+    a: F::Bits,
+    b: F::Bits,
+    expected: F::Bits,
+    flags: ExceptionFlags,
+) {
+    let mut env = FpEnv::default();
+    let d = op(a, b, &mut env).unwrap_or_else(|| panic!("{} has no {} {}", B::NAME, F::NAME, name));
+    assert_eq!(d, expected, "{} {} {}", B::NAME, F::NAME, name);
+    if B::EXCEPTION_FLAGS {
+        assert_eq!(
+            env.flags,
+            flags,
+            "{} {} {} exception flags",
+            B::NAME,
+            F::NAME,
+            name
+        );
+    }
+    bencher.iter(|| {
+        let d = op(test::black_box(a), test::black_box(b), &mut env);
+        assert_eq!(d, Some(expected));
+        d
+    });
+}
+
+/// Benchmarks [`MinMax::minimum`].
+pub fn minimum<F: Format, B: MinMax<F>>(
+    bencher: &mut Bencher,
+    _mode: RoundingMode,
+    a: F::Bits,
+    b: F::Bits,
+    expected: F::Bits,
+    flags: ExceptionFlags,
+) {
+    min_max::<F, B>(bencher, "minimum", B::minimum, a, b, expected, flags);
+}
+
+/// Benchmarks [`MinMax::maximum`].
+pub fn maximum<F: Format, B: MinMax<F>>(
+    bencher: &mut Bencher,
+    _mode: RoundingMode,
+    a: F::Bits,
+    b: F::Bits,
+    expected: F::Bits,
+    flags: ExceptionFlags,
+) {
+    min_max::<F, B>(bencher, "maximum", B::maximum, a, b, expected, flags);
+}
+
+/// Benchmarks [`MinMax::min_num`].
+pub fn min_num<F: Format, B: MinMax<F>>(
+    bencher: &mut Bencher,
+    _mode: RoundingMode,
+    a: F::Bits,
+    b: F::Bits,
+    expected: F::Bits,
+    flags: ExceptionFlags,
+) {
+    min_max::<F, B>(bencher, "min_num", B::min_num, a, b, expected, flags);
+}
+
+/// Benchmarks [`MinMax::max_num`].
+pub fn max_num<F: Format, B: MinMax<F>>(
+    bencher: &mut Bencher,
+    _mode: RoundingMode,
+    a: F::Bits,
+    b: F::Bits,
+    expected: F::Bits,
+    flags: ExceptionFlags,
+) {
+    min_max::<F, B>(bencher, "max_num", B::max_num, a, b, expected, flags);
+}
+
+/// Benchmarks [`Convert`] from binary32 to `F`.
+pub fn from_f32<F: Format, B: Convert<Binary32, F>>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    a: u32,
+    expected: F::Bits,
+    flags: ExceptionFlags,
+) {
+    convert::<Binary32, F, B>(bencher, mode, a, expected, flags);
+}
+
+/// Benchmarks [`Convert`] from `F` to binary32.
+pub fn to_f32<F: Format, B: Convert<F, Binary32>>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    a: F::Bits,
+    expected: u32,
+    flags: ExceptionFlags,
+) {
+    convert::<F, Binary32, B>(bencher, mode, a, expected, flags);
+}
+
+/// Benchmarks [`Convert`] from binary64 to `F`.
+pub fn from_f64<F: Format, B: Convert<Binary64, F>>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    a: u64,
+    expected: F::Bits,
+    flags: ExceptionFlags,
+) {
+    convert::<Binary64, F, B>(bencher, mode, a, expected, flags);
+}
+
+/// Benchmarks [`Convert`] from binary128 to `F`.
+pub fn from_f128<F: Format, B: Convert<Binary128, F>>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    a: u128,
+    expected: F::Bits,
+    flags: ExceptionFlags,
+) {
+    convert::<Binary128, F, B>(bencher, mode, a, expected, flags);
+}
+
+/// Benchmarks [`Convert`] from `F` to binary64.
+pub fn to_f64<F: Format, B: Convert<F, Binary64>>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    a: F::Bits,
+    expected: u64,
+    flags: ExceptionFlags,
+) {
+    convert::<F, Binary64, B>(bencher, mode, a, expected, flags);
+}
+
+/// Benchmarks [`Convert`] from `F` to binary128.
+pub fn to_f128<F: Format, B: Convert<F, Binary128>>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    a: F::Bits,
+    expected: u128,
+    flags: ExceptionFlags,
+) {
+    convert::<F, Binary128, B>(bencher, mode, a, expected, flags);
+}
+
+/// Benchmarks [`SoftFloatBackend::rem`].
+pub fn rem<F: Format, B: SoftFloatBackend<F>>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    a: F::Bits,
+    b: F::Bits,
+    expected: F::Bits,
+    flags: ExceptionFlags,
+) {
+    binary::<F, B>(bencher, mode, [a, b], expected, flags, |a, b, env| {
+        B::rem(a, b, env).unwrap_or_else(|| panic!("{} has no {} rem", B::NAME, F::NAME))
+    });
+}
+
+/// Benchmarks [`SoftFloatBackend::round_to_integral`].
+pub fn round_to_integral<F: Format, B: SoftFloatBackend<F>>(
+    bencher: &mut Bencher,
+    mode: RoundingMode,
+    a: F::Bits,
+    expected: F::Bits,
+    flags: ExceptionFlags,
+) {
+    unary::<F, B>(bencher, mode, [a], expected, flags, |a, env| {
+        B::round_to_integral(a, env)
+            .unwrap_or_else(|| panic!("{} has no {} round_to_integral", B::NAME, F::NAME))
+    });
+}
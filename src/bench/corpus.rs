@@ -0,0 +1,50 @@
+//! Benchmark bodies for the edge-case corpus.
+//!
+//! Every iteration performs [`LEN`] operations on operands cycled from one
+//! class of [`crate::edge_cases`], so timings of different classes compare
+//! directly. Results are not checked: the corpus is full of NaNs, whose
+//! payloads legitimately differ between libraries.
+
+use super::{binary_many, unary_many};
+use crate::backend::SoftFloatBackend;
+use crate::format::Format;
+use test::Bencher;
+
+/// Operations per iteration.
+pub const LEN: usize = 64;
+
+/// Every ordered pair of the class, repeated up to [`LEN`] pairs.
+fn pairs<F: Format>(class: fn() -> Vec<F::Bits>) -> Vec<(F::Bits, F::Bits)> {
+    let operands = class();
+    let n = operands.len();
+    (0..LEN)
+        .map(|i| (operands[i % n], operands[i / n % n]))
+        .collect()
+}
+
+/// The class, repeated up to [`LEN`] operands.
+fn operands<F: Format>(class: fn() -> Vec<F::Bits>) -> Vec<F::Bits> {
+    class().into_iter().cycle().take(LEN).collect()
+}
+
+/// Benchmarks [`SoftFloatBackend::add`] over a corpus class.
+pub fn add<F: Format, B: SoftFloatBackend<F>>(bencher: &mut Bencher, class: fn() -> Vec<F::Bits>) {
+    binary_many::<F, B>(bencher, &pairs::<F>(class), B::add);
+}
+
+/// Benchmarks [`SoftFloatBackend::mul`] over a corpus class.
+pub fn mul<F: Format, B: SoftFloatBackend<F>>(bencher: &mut Bencher, class: fn() -> Vec<F::Bits>) {
+    binary_many::<F, B>(bencher, &pairs::<F>(class), B::mul);
+}
+
+/// Benchmarks [`SoftFloatBackend::div`] over a corpus class.
+pub fn div<F: Format, B: SoftFloatBackend<F>>(bencher: &mut Bencher, class: fn() -> Vec<F::Bits>) {
+    binary_many::<F, B>(bencher, &pairs::<F>(class), B::div);
+}
+
+/// Benchmarks [`SoftFloatBackend::sqrt`] over a corpus class.
+pub fn sqrt<F: Format, B: SoftFloatBackend<F>>(bencher: &mut Bencher, class: fn() -> Vec<F::Bits>) {
+    unary_many::<F, B>(bencher, &operands::<F>(class), |a, env| {
+        B::sqrt(a, env).unwrap_or_else(|| panic!("{} has no {} sqrt", B::NAME, F::NAME))
+    });
+}
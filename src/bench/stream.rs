@@ -0,0 +1,61 @@
+//! Benchmark bodies for seeded random operand streams.
+//!
+//! Every iteration performs [`LEN`] operations on operands drawn from one
+//! distribution of [`crate::random`], long enough that branch prediction
+//! cannot learn the stream. Divide ns/iter by [`LEN`] for the cost of one
+//! operation. Results are not checked, as streams may contain NaNs.
+
+use super::{binary_many, unary_many};
+use crate::backend::SoftFloatBackend;
+use crate::format::Format;
+use crate::random::{Rng, SEED};
+use test::Bencher;
+
+/// Operations per iteration.
+pub const LEN: usize = 1024;
+
+fn pairs<F: Format>(distribution: fn(&mut Rng) -> F::Bits) -> Vec<(F::Bits, F::Bits)> {
+    let mut rng = Rng::new(SEED);
+    (0..LEN)
+        .map(|_| (distribution(&mut rng), distribution(&mut rng)))
+        .collect()
+}
+
+fn operands<F: Format>(distribution: fn(&mut Rng) -> F::Bits) -> Vec<F::Bits> {
+    let mut rng = Rng::new(SEED);
+    (0..LEN).map(|_| distribution(&mut rng)).collect()
+}
+
+/// Benchmarks [`SoftFloatBackend::add`] over a stream.
+pub fn add<F: Format, B: SoftFloatBackend<F>>(
+    bencher: &mut Bencher,
+    distribution: fn(&mut Rng) -> F::Bits,
+) {
+    binary_many::<F, B>(bencher, &pairs::<F>(distribution), B::add);
+}
+
+/// Benchmarks [`SoftFloatBackend::mul`] over a stream.
+pub fn mul<F: Format, B: SoftFloatBackend<F>>(
+    bencher: &mut Bencher,
+    distribution: fn(&mut Rng) -> F::Bits,
+) {
+    binary_many::<F, B>(bencher, &pairs::<F>(distribution), B::mul);
+}
+
+/// Benchmarks [`SoftFloatBackend::div`] over a stream.
+pub fn div<F: Format, B: SoftFloatBackend<F>>(
+    bencher: &mut Bencher,
+    distribution: fn(&mut Rng) -> F::Bits,
+) {
+    binary_many::<F, B>(bencher, &pairs::<F>(distribution), B::div);
+}
+
+/// Benchmarks [`SoftFloatBackend::sqrt`] over a stream.
+pub fn sqrt<F: Format, B: SoftFloatBackend<F>>(
+    bencher: &mut Bencher,
+    distribution: fn(&mut Rng) -> F::Bits,
+) {
+    unary_many::<F, B>(bencher, &operands::<F>(distribution), |a, env| {
+        B::sqrt(a, env).unwrap_or_else(|| panic!("{} has no {} sqrt", B::NAME, F::NAME))
+    });
+}
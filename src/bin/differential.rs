@@ -0,0 +1,141 @@
+//! Feeds the same seeded random operands to every backend and reports where
+//! their results differ from the correctly rounded ones.
+//!
+//! ```text
+//! cargo run --release --bin differential -- [--count N] [--seed S]
+//!     [--nan-payloads] [--platform riscv|x86|arm] [--show N]
+//!     [--ops add,mul,div] [f16 f32 f64 f128 bf16]
+//! ```
+
+use softfloat_bench::backend::{Backends, Op};
+use softfloat_bench::differential::{self, Mismatch, Options};
+use softfloat_bench::format::{BFloat16, Binary128, Binary16, Binary32, Binary64, Format};
+use softfloat_bench::platform::Platform;
+use std::process;
+
+fn usage() -> ! {
+    eprintln!(
+        "usage: differential [--count N] [--seed S] [--nan-payloads] \
+         [--platform riscv|x86|arm] [--show N] [--ops add,mul,div] [f16 f32 f64 f128 bf16]"
+    );
+    process::exit(2);
+}
+
+fn parse<T: std::str::FromStr>(value: Option<String>) -> T {
+    value
+        .and_then(|value| value.parse().ok())
+        .unwrap_or_else(|| usage())
+}
+
+fn hex<F: Format>(bits: F::Bits) -> String {
+    format!("{:#x}", F::widen(bits))
+}
+
+fn check<F: Backends>(ops: &[Op], options: &Options, show: usize) -> u64 {
+    if let Some(platform) = options.platform {
+        for backend in F::backends() {
+            if !backend.platforms.contains(&platform) {
+                println!(
+                    "{} {}: does not model the {} platform, left out",
+                    F::NAME,
+                    backend.name,
+                    platform.name
+                );
+            }
+        }
+    }
+    let mut shown = 0;
+    let summary = differential::run::<F>(ops, options, |mismatch: &Mismatch<F>| {
+        if shown < show {
+            shown += 1;
+            let operands: Vec<_> = mismatch.operands.iter().map(|&a| hex::<F>(a)).collect();
+            let results: Vec<_> = mismatch
+                .results
+                .iter()
+                .map(|&(name, result)| format!("{}={}", name, hex::<F>(result)))
+                .collect();
+            println!(
+                "{} {} {} ({}): reference={} {}",
+                F::NAME,
+                mismatch.op.name(),
+                mismatch.mode.name(),
+                operands.join(", "),
+                hex::<F>(mismatch.reference),
+                results.join(" ")
+            );
+        }
+    });
+    println!(
+        "{}: {} cases, {} mismatches",
+        F::NAME,
+        summary.cases,
+        summary.mismatches
+    );
+    for ((op, name), count) in &summary.incorrect {
+        println!("  {} {}: incorrect {} times", op.name(), name, count);
+    }
+    for ((op, name), count) in &summary.outvoted {
+        println!("  {} {}: outvoted {} times", op.name(), name, count);
+    }
+    summary.mismatches
+}
+
+fn main() {
+    let mut options = Options::default();
+    let mut show = 10;
+    let mut ops = vec![Op::Add, Op::Mul, Op::Div];
+    let mut formats = Vec::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--count" => options.count = parse(args.next()),
+            "--seed" => options.seed = parse(args.next()),
+            "--nan-payloads" => options.nan_payloads = true,
+            "--platform" => {
+                let name: String = parse(args.next());
+                let platform = Platform::ALL.iter().find(|platform| platform.name == name);
+                options.platform = Some(*platform.unwrap_or_else(|| usage()));
+            }
+            "--show" => show = parse(args.next()),
+            "--ops" => {
+                let names: String = parse(args.next());
+                ops = names
+                    .split(',')
+                    .map(|name| {
+                        Op::ALL
+                            .iter()
+                            .copied()
+                            .find(|op| op.name() == name)
+                            .unwrap_or_else(|| usage())
+                    })
+                    .collect();
+            }
+            "f16" | "f32" | "f64" | "f128" | "bf16" => formats.push(arg),
+            _ => usage(),
+        }
+    }
+    if formats.is_empty() {
+        formats = vec![
+            "f16".into(),
+            "f32".into(),
+            "f64".into(),
+            "f128".into(),
+            "bf16".into(),
+        ];
+    }
+
+    let mut mismatches = 0;
+    for format in &formats {
+        mismatches += match format.as_str() {
+            "f16" => check::<Binary16>(&ops, &options, show),
+            "f32" => check::<Binary32>(&ops, &options, show),
+            "f64" => check::<Binary64>(&ops, &options, show),
+            "f128" => check::<Binary128>(&ops, &options, show),
+            "bf16" => check::<BFloat16>(&ops, &options, show),
+            _ => unreachable!(),
+        };
+    }
+    if mismatches > 0 {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,110 @@
+//! Checks every binary16 operand pair against an MPFR oracle and writes a
+//! summary of the mismatching pairs per operation.
+//!
+//! ```text
+//! cargo run --release --bin exhaustive_f16 -- [--threads N]
+//!     [--ops add,mul,div] [--modes rne,rtz,rup,rdn,rna] [--output PATH]
+//! ```
+
+use softfloat_bench::backend::Op;
+use softfloat_bench::env::RoundingMode;
+use softfloat_bench::exhaustive::{self, Summary};
+use std::fmt::Write as _;
+use std::{fs, process, thread};
+
+fn usage() -> ! {
+    eprintln!(
+        "usage: exhaustive_f16 [--threads N] [--ops add,mul,div] \
+         [--modes rne,rtz,rup,rdn,rna] [--output PATH]"
+    );
+    process::exit(2);
+}
+
+fn parse<T: std::str::FromStr>(value: Option<String>) -> T {
+    value
+        .and_then(|value| value.parse().ok())
+        .unwrap_or_else(|| usage())
+}
+
+/// Parses a comma-separated list of names into the matching items of `all`.
+fn parse_list<T: Copy>(value: Option<String>, all: &[T], name: fn(T) -> &'static str) -> Vec<T> {
+    let names: String = parse(value);
+    names
+        .split(',')
+        .map(|n| {
+            all.iter()
+                .copied()
+                .find(|&item| name(item) == n)
+                .unwrap_or_else(|| usage())
+        })
+        .collect()
+}
+
+fn report(ops: &[Op], modes: &[RoundingMode], summary: &Summary) -> String {
+    let mut out = String::new();
+    for &op in ops {
+        for &mode in modes {
+            let mut clean = true;
+            for ((_, _, name), mismatches) in summary
+                .iter()
+                .filter(|((o, m, _), _)| *o == op && *m == mode.name())
+            {
+                clean = false;
+                writeln!(
+                    out,
+                    "{} {} {}: {} mismatching pairs",
+                    op.name(),
+                    mode.name(),
+                    name,
+                    mismatches.count
+                )
+                .unwrap();
+                for &([a, b], result, expected) in &mismatches.examples {
+                    writeln!(
+                        out,
+                        "  {:#06x}, {:#06x}: got {:#06x}, expected {:#06x}",
+                        a, b, result, expected
+                    )
+                    .unwrap();
+                }
+            }
+            if clean {
+                writeln!(out, "{} {}: no mismatches", op.name(), mode.name()).unwrap();
+            }
+        }
+    }
+    out
+}
+
+fn main() {
+    let mut threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let mut ops = vec![Op::Add, Op::Mul, Op::Div];
+    let mut modes = vec![RoundingMode::TiesToEven];
+    let mut output = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--threads" => threads = parse(args.next()),
+            "--ops" => ops = parse_list(args.next(), &exhaustive::OPS, Op::name),
+            "--modes" => modes = parse_list(args.next(), &RoundingMode::ALL, RoundingMode::name),
+            "--output" => output = Some(parse::<String>(args.next())),
+            _ => usage(),
+        }
+    }
+    if threads == 0 {
+        usage();
+    }
+
+    let summary = exhaustive::run(&ops, &modes, threads);
+    let report = report(&ops, &modes, &summary);
+    print!("{}", report);
+    if let Some(path) = output {
+        fs::write(&path, &report).unwrap_or_else(|error| {
+            eprintln!("cannot write {}: {}", path, error);
+            process::exit(2);
+        });
+    }
+    if !summary.is_empty() {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,104 @@
+//! Tabulates the integer each backend returns for NaNs, infinities and
+//! out-of-range operands, and whether it signals invalid.
+//!
+//! ```text
+//! cargo run --release --bin int_report -- [f16 f32 f64 f128]
+//! ```
+
+use softfloat_bench::backend::IntBackends;
+use softfloat_bench::format::{Binary128, Binary16, Binary32, Binary64, Int};
+use softfloat_bench::int_conversion::{self, Outcome};
+use std::process;
+
+const LEGEND: &str = "\
+Each cell is the integer returned (none: the backend returns no integer),
+then whether invalid was signaled (I: yes, -: no, ?: the backend reports no
+flags). Conversions round to nearest, ties to even.";
+
+fn usage() -> ! {
+    eprintln!("usage: int_report [f16 f32 f64 f128]");
+    process::exit(2);
+}
+
+fn cell<I: Int>(outcome: &Outcome<I>) -> String {
+    let value = match outcome.value {
+        Some(value) => value.to_string(),
+        None => "none".into(),
+    };
+    let invalid = match outcome.invalid {
+        Some(true) => "I",
+        Some(false) => "-",
+        None => "?",
+    };
+    format!("{} {}", value, invalid)
+}
+
+fn report<F: IntBackends<I>, I: Int>() {
+    let mut table = vec![];
+    let mut header = vec![format!("{} to {}", F::NAME, I::NAME)];
+    header.extend(
+        F::int_backends()
+            .iter()
+            .map(|backend| backend.name.to_string()),
+    );
+    table.push(header);
+    for row in int_conversion::run::<F, I>() {
+        let mut line = vec![format!("{} ({:#x})", row.name, F::widen(row.bits))];
+        line.extend(row.outcomes.iter().map(cell::<I>));
+        table.push(line);
+    }
+
+    let widths: Vec<_> = (0..table[0].len())
+        .map(|column| table.iter().map(|line| line[column].len()).max().unwrap())
+        .collect();
+    for line in &table {
+        let cells: Vec<_> = line
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:width$}", cell, width = width))
+            .collect();
+        println!("{}", cells.join(" | ").trim_end());
+    }
+    println!();
+}
+
+fn report_ints<F>()
+where
+    F: IntBackends<i32>
+        + IntBackends<u32>
+        + IntBackends<i64>
+        + IntBackends<u64>
+        + IntBackends<i128>
+        + IntBackends<u128>,
+{
+    report::<F, i32>();
+    report::<F, u32>();
+    report::<F, i64>();
+    report::<F, u64>();
+    report::<F, i128>();
+    report::<F, u128>();
+}
+
+fn main() {
+    let mut formats: Vec<_> = std::env::args().skip(1).collect();
+    if formats
+        .iter()
+        .any(|format| !["f16", "f32", "f64", "f128"].contains(&format.as_str()))
+    {
+        usage();
+    }
+    if formats.is_empty() {
+        formats = vec!["f16".into(), "f32".into(), "f64".into(), "f128".into()];
+    }
+
+    println!("{}\n", LEGEND);
+    for format in &formats {
+        match format.as_str() {
+            "f16" => report_ints::<Binary16>(),
+            "f32" => report_ints::<Binary32>(),
+            "f64" => report_ints::<Binary64>(),
+            "f128" => report_ints::<Binary128>(),
+            _ => unreachable!(),
+        }
+    }
+}
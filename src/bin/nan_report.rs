@@ -0,0 +1,136 @@
+//! Tabulates the NaN each backend returns for every operation on quiet and
+//! signaling NaN operands, and for the invalid operations.
+//!
+//! ```text
+//! cargo run --release --bin nan_report -- [--ops add,mul,div]
+//!     [--platform riscv|x86|arm] [f16 f32 f64 f128 bf16]
+//! ```
+
+use softfloat_bench::backend::{Backends, Op};
+use softfloat_bench::format::{BFloat16, Binary128, Binary16, Binary32, Binary64, Format};
+use softfloat_bench::nan_propagation::{self, Outcome, Source};
+use softfloat_bench::platform::Platform;
+use std::process;
+
+const LEGEND: &str = "\
+Each cell is the result's bits, then where its payload came from (a, b, c:
+that operand; dflt: empty payload; other: none of them; num: not a NaN),
+whether it is quiet (q) or signaling (s), and whether invalid was signaled
+(I: yes, -: no, ?: the backend reports no flags). Empty cells are operations
+the backend lacks. Backends marked with * model the platform profile and
+configure themselves for it; the others behave as they always do.";
+
+fn usage() -> ! {
+    eprintln!(
+        "usage: nan_report [--ops add,mul,div] [--platform riscv|x86|arm] [f16 f32 f64 f128 bf16]"
+    );
+    process::exit(2);
+}
+
+fn cell<F: Format>(outcome: &Option<Outcome<F>>) -> String {
+    let outcome = match outcome {
+        Some(outcome) => outcome,
+        None => return String::new(),
+    };
+    let source = match outcome.source {
+        Source::Operand(i) => ((b'a' + i as u8) as char).to_string(),
+        Source::Default => "dflt".into(),
+        Source::Other => "other".into(),
+        Source::Number => "num".into(),
+    };
+    let invalid = match outcome.invalid {
+        Some(true) => "I",
+        Some(false) => "-",
+        None => "?",
+    };
+    format!(
+        "{:#x} {} {} {}",
+        F::widen(outcome.bits),
+        source,
+        if outcome.quiet { "q" } else { "s" },
+        invalid
+    )
+}
+
+fn report<F: Backends>(ops: &[Op], platform: Platform) {
+    let mut table = vec![];
+    let mut header = vec![format!("{} on {}", F::NAME, platform.name)];
+    header.extend(F::backends().iter().map(|backend| {
+        let modelled = backend.platforms.contains(&platform);
+        format!("{}{}", backend.name, if modelled { "*" } else { "" })
+    }));
+    table.push(header);
+    for row in nan_propagation::run::<F>(ops, platform) {
+        let operands: Vec<_> = row.operands.iter().map(|&(name, _)| name).collect();
+        let mut line = vec![format!("{}({})", row.op.name(), operands.join(", "))];
+        line.extend(row.outcomes.iter().map(cell::<F>));
+        table.push(line);
+    }
+
+    let widths: Vec<_> = (0..table[0].len())
+        .map(|column| table.iter().map(|line| line[column].len()).max().unwrap())
+        .collect();
+    for line in &table {
+        let cells: Vec<_> = line
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:width$}", cell, width = width))
+            .collect();
+        println!("{}", cells.join(" | ").trim_end());
+    }
+    println!();
+}
+
+fn main() {
+    let mut ops = Op::ALL.to_vec();
+    let mut platform = Platform::default();
+    let mut formats = Vec::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--ops" => {
+                let names = args.next().unwrap_or_else(|| usage());
+                ops = names
+                    .split(',')
+                    .map(|name| {
+                        Op::ALL
+                            .iter()
+                            .copied()
+                            .find(|op| op.name() == name)
+                            .unwrap_or_else(|| usage())
+                    })
+                    .collect();
+            }
+            "--platform" => {
+                let name = args.next().unwrap_or_else(|| usage());
+                platform = *Platform::ALL
+                    .iter()
+                    .find(|platform| platform.name == name)
+                    .unwrap_or_else(|| usage());
+            }
+            "f16" | "f32" | "f64" | "f128" | "bf16" => formats.push(arg),
+            _ => usage(),
+        }
+    }
+    if formats.is_empty() {
+        formats = vec![
+            "f16".into(),
+            "f32".into(),
+            "f64".into(),
+            "f128".into(),
+            "bf16".into(),
+        ];
+    }
+
+    println!("{}\n", LEGEND);
+    for format in &formats {
+        match format.as_str() {
+            "f16" => report::<Binary16>(&ops, platform),
+            "f32" => report::<Binary32>(&ops, platform),
+            "f64" => report::<Binary64>(&ops, platform),
+            "f128" => report::<Binary128>(&ops, platform),
+            "bf16" => report::<BFloat16>(&ops, platform),
+            _ => unreachable!(),
+        }
+    }
+}
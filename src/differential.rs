@@ -0,0 +1,155 @@
+//! Differential testing: the same operands through every backend.
+//!
+//! The benches check a single expected result per operation; this checks
+//! that the libraries agree with each other over millions of seeded random
+//! operands, in every rounding mode each of them supports, and with the
+//! correctly rounded results of [`reference`].
+
+use crate::backend::{Backends, Op};
+use crate::env::{FpEnv, RoundingMode};
+use crate::format::Format;
+use crate::platform::Platform;
+use crate::random::{self, Rng};
+use crate::reference;
+use std::collections::BTreeMap;
+
+/// What to run and how to compare.
+#[derive(Clone, Debug)]
+pub struct Options {
+    /// Operand tuples per operation; each runs in every rounding mode.
+    pub count: u64,
+    pub seed: u64,
+    /// Compare NaN results bit for bit instead of only as NaNs, so that
+    /// payload and sign propagation count as mismatches.
+    pub nan_payloads: bool,
+    /// Run on this platform profile, leaving out the backends that do not
+    /// model it; otherwise every backend runs on its own terms.
+    pub platform: Option<Platform>,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            count: 1_000_000,
+            seed: random::SEED,
+            nan_payloads: false,
+            platform: None,
+        }
+    }
+}
+
+/// One operation on one operand tuple on which some backend disagrees with
+/// the reference.
+pub struct Mismatch<F: Format> {
+    pub op: Op,
+    pub mode: RoundingMode,
+    pub operands: Vec<F::Bits>,
+    /// The correctly rounded result.
+    pub reference: F::Bits,
+    /// Every participating backend's result, in [`Backends::backends`] order.
+    pub results: Vec<(&'static str, F::Bits)>,
+}
+
+/// Totals of one run over a format.
+#[derive(Debug, Default)]
+pub struct Summary {
+    /// Operand tuples checked, counting each operation and rounding mode.
+    pub cases: u64,
+    pub mismatches: u64,
+    /// How often each backend disagreed with the majority, by operation.
+    pub outvoted: BTreeMap<(Op, &'static str), u64>,
+    /// How often each backend disagreed with the reference, by operation.
+    pub incorrect: BTreeMap<(Op, &'static str), u64>,
+}
+
+/// Draws operands from every distribution in turn, so one run covers specials,
+/// subnormals and ordinary numbers alike.
+fn distributions<F: Format>() -> [fn(&mut Rng) -> F::Bits; 5] {
+    [
+        random::uniform_bits::<F>,
+        random::uniform_finite::<F>,
+        random::log_uniform::<F>,
+        random::near_one::<F>,
+        random::subnormal_heavy::<F>,
+    ]
+}
+
+fn same<F: Format>(a: F::Bits, b: F::Bits, nan_payloads: bool) -> bool {
+    a == b || (!nan_payloads && F::is_nan(a) && F::is_nan(b))
+}
+
+/// Runs each of `ops` on `options.count` operand tuples through every backend
+/// of `F`, calling `on_mismatch` for every disagreement.
+pub fn run<F: Backends>(
+    ops: &[Op],
+    options: &Options,
+    mut on_mismatch: impl FnMut(&Mismatch<F>),
+) -> Summary {
+    let mut backends = F::backends();
+    if let Some(platform) = options.platform {
+        backends.retain(|backend| backend.platforms.contains(&platform));
+    }
+    let platform = options.platform.unwrap_or_default();
+    let distributions = distributions::<F>();
+    let mut summary = Summary::default();
+    for &op in ops {
+        let mut rng = Rng::new(options.seed);
+        for i in 0..options.count {
+            let distribution = distributions[i as usize % distributions.len()];
+            let operands: Vec<_> = (0..op.arity()).map(|_| distribution(&mut rng)).collect();
+            for &mode in &RoundingMode::ALL {
+                let results: Vec<_> = backends
+                    .iter()
+                    .filter(|backend| backend.rounding_modes.contains(&mode))
+                    .filter_map(|backend| {
+                        let mut env = FpEnv {
+                            platform,
+                            ..FpEnv::new(mode)
+                        };
+                        let result = backend.apply(op, &operands, &mut env)?;
+                        Some((backend.name, result))
+                    })
+                    .collect();
+                let mut env = FpEnv {
+                    platform,
+                    ..FpEnv::new(mode)
+                };
+                let reference = reference::apply::<F>(op, &operands, &mut env);
+                summary.cases += 1;
+                if results
+                    .iter()
+                    .all(|&(_, result)| same::<F>(result, reference, options.nan_payloads))
+                {
+                    continue;
+                }
+                summary.mismatches += 1;
+                let majority = results
+                    .iter()
+                    .max_by_key(|&&(_, a)| {
+                        let votes = results
+                            .iter()
+                            .filter(|&&(_, b)| same::<F>(a, b, options.nan_payloads));
+                        votes.count()
+                    })
+                    .unwrap()
+                    .1;
+                for &(name, result) in &results {
+                    if !same::<F>(result, majority, options.nan_payloads) {
+                        *summary.outvoted.entry((op, name)).or_default() += 1;
+                    }
+                    if !same::<F>(result, reference, options.nan_payloads) {
+                        *summary.incorrect.entry((op, name)).or_default() += 1;
+                    }
+                }
+                on_mismatch(&Mismatch {
+                    op,
+                    mode,
+                    operands: operands.clone(),
+                    reference,
+                    results,
+                });
+            }
+        }
+    }
+    summary
+}
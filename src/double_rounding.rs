@@ -0,0 +1,57 @@
+//! Binary16 operations that used to be rounded twice.
+//!
+//! rug's binary16 results were first rounded to 11 bits with MPFR's unbounded
+//! exponent range and then again by `half::f16::from_f32` onto the subnormal
+//! grid. Each case below has a subnormal result where the first rounding
+//! lands on a tie of the second, so that path was one ulp off the correctly
+//! rounded result returned by softfloat-sys.
+
+use crate::backend::Op;
+use crate::bench::Case;
+use crate::format::Binary16;
+
+/// Cases rounding to nearest with ties to even; the comments give the
+/// double-rounded results.
+pub const F16: [Case<Binary16>; 9] = [
+    (Op::Mul, [0x0003, 0x5979], 0x020d), // 0x020e
+    (Op::Mul, [0x000a, 0x5395], 0x025f), // 0x025e
+    (Op::Mul, [0x132b, 0x2c0b], 0x039f), // 0x03a0
+    (Op::Mul, [0x932b, 0x2c0b], 0x839f), // 0x83a0
+    (Op::Mul, [0x1739, 0x189b], 0x0043), // 0x0042
+    (Op::Div, [0x0005, 0x1fb2], 0x0299), // 0x029a
+    (Op::Div, [0x000b, 0x2a90], 0x00d7), // 0x00d6
+    (Op::Div, [0x0618, 0x40d7], 0x0285), // 0x0284
+    (Op::Div, [0x1845, 0xd546], 0x819f), // 0x819e
+];
+
+#[cfg(test)]
+mod f16 {
+    use crate::backend::*;
+    use crate::env::RoundingMode;
+    use crate::format::Binary16;
+    use test::Bencher;
+
+    macro_rules! cases {
+        ($($name:ident: $backend:ty,)*) => {
+            $(
+                #[bench]
+                fn $name(b: &mut Bencher) {
+                    crate::bench::cases::<Binary16, $backend>(
+                        b,
+                        RoundingMode::TiesToEven,
+                        &super::F16,
+                    );
+                }
+            )*
+        };
+    }
+
+    cases! {
+        simple_soft_float: SimpleSoftFloat,
+        softfloat_sys: SoftfloatSys,
+        half: Half,
+        rug: Rug,
+        rustc_apfloat: RustcApfloat,
+        native: Native,
+    }
+}
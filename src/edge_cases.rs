@@ -0,0 +1,73 @@
+//! Named classes of edge-case operands for the corpus benches.
+//!
+//! Each class holds a handful of encodings of the format, both signs of each,
+//! chosen to drive libraries through one particular path: special-value
+//! handling, subnormal normalization, overflow, or rounding of ties.
+
+use crate::format::Format;
+
+/// Encodes each magnitude, given as biased exponent and significand field,
+/// with a positive and then a negative sign.
+fn signed<F: Format>(magnitudes: &[(u128, u128)]) -> Vec<F::Bits> {
+    magnitudes
+        .iter()
+        .flat_map(|&(exponent, mantissa)| {
+            vec![
+                F::encode(false, exponent, mantissa),
+                F::encode(true, exponent, mantissa),
+            ]
+        })
+        .collect()
+}
+
+/// ±0.
+pub fn zero<F: Format>() -> Vec<F::Bits> {
+    signed::<F>(&[(0, 0)])
+}
+
+/// The smallest and largest subnormals.
+pub fn subnormal<F: Format>() -> Vec<F::Bits> {
+    signed::<F>(&[(0, 1), (0, F::mantissa_mask())])
+}
+
+/// The smallest normal number.
+pub fn min_normal<F: Format>() -> Vec<F::Bits> {
+    signed::<F>(&[(1, 0)])
+}
+
+/// The largest finite number.
+pub fn max_finite<F: Format>() -> Vec<F::Bits> {
+    signed::<F>(&[(F::max_exponent() - 1, F::mantissa_mask())])
+}
+
+/// ±∞.
+pub fn infinity<F: Format>() -> Vec<F::Bits> {
+    vec![F::infinity(false), F::infinity(true)]
+}
+
+/// The default quiet NaN and one with a payload.
+pub fn quiet_nan<F: Format>() -> Vec<F::Bits> {
+    signed::<F>(&[
+        (F::max_exponent(), F::quiet_bit()),
+        (F::max_exponent(), F::quiet_bit() | 1),
+    ])
+}
+
+/// Signaling NaNs with the smallest and largest payloads.
+pub fn signaling_nan<F: Format>() -> Vec<F::Bits> {
+    signed::<F>(&[
+        (F::max_exponent(), 1),
+        (F::max_exponent(), F::quiet_bit() - 1),
+    ])
+}
+
+/// 1.0 and its neighbours, together with half an ulp of 1.0, so that sums
+/// and products land exactly on or next to ties.
+pub fn boundary<F: Format>() -> Vec<F::Bits> {
+    signed::<F>(&[
+        (F::bias(), 0),
+        (F::bias(), 1),
+        (F::bias(), F::mantissa_mask()),
+        (F::bias() - F::MANTISSA_BITS as u128 - 1, 0),
+    ])
+}
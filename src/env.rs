@@ -0,0 +1,130 @@
+//! The floating-point environment operations run in.
+
+use crate::platform::Platform;
+use std::fmt;
+use std::ops::{BitOr, BitOrAssign};
+
+/// IEEE 754 rounding-direction attributes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// roundTiesToEven
+    #[default]
+    TiesToEven,
+    /// roundTowardZero
+    TowardZero,
+    /// roundTowardPositive
+    TowardPositive,
+    /// roundTowardNegative
+    TowardNegative,
+    /// roundTiesToAway
+    TiesToAway,
+}
+
+impl RoundingMode {
+    pub const ALL: [RoundingMode; 5] = [
+        RoundingMode::TiesToEven,
+        RoundingMode::TowardZero,
+        RoundingMode::TowardPositive,
+        RoundingMode::TowardNegative,
+        RoundingMode::TiesToAway,
+    ];
+
+    /// Short name used in benchmark paths and reports, e.g. `rtz`.
+    pub fn name(self) -> &'static str {
+        match self {
+            RoundingMode::TiesToEven => "rne",
+            RoundingMode::TowardZero => "rtz",
+            RoundingMode::TowardPositive => "rup",
+            RoundingMode::TowardNegative => "rdn",
+            RoundingMode::TiesToAway => "rna",
+        }
+    }
+}
+
+/// IEEE 754 exception flags, normalized across libraries.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExceptionFlags(u8);
+
+impl ExceptionFlags {
+    pub const NONE: ExceptionFlags = ExceptionFlags(0);
+    pub const INVALID: ExceptionFlags = ExceptionFlags(1 << 0);
+    pub const DIVIDE_BY_ZERO: ExceptionFlags = ExceptionFlags(1 << 1);
+    pub const OVERFLOW: ExceptionFlags = ExceptionFlags(1 << 2);
+    pub const UNDERFLOW: ExceptionFlags = ExceptionFlags(1 << 3);
+    pub const INEXACT: ExceptionFlags = ExceptionFlags(1 << 4);
+
+    const NAMES: [(ExceptionFlags, &'static str); 5] = [
+        (ExceptionFlags::INVALID, "invalid"),
+        (ExceptionFlags::DIVIDE_BY_ZERO, "divide_by_zero"),
+        (ExceptionFlags::OVERFLOW, "overflow"),
+        (ExceptionFlags::UNDERFLOW, "underflow"),
+        (ExceptionFlags::INEXACT, "inexact"),
+    ];
+
+    pub fn is_empty(self) -> bool {
+        self == ExceptionFlags::NONE
+    }
+
+    pub fn contains(self, other: ExceptionFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for ExceptionFlags {
+    type Output = ExceptionFlags;
+
+    fn bitor(self, rhs: ExceptionFlags) -> ExceptionFlags {
+        ExceptionFlags(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for ExceptionFlags {
+    fn bitor_assign(&mut self, rhs: ExceptionFlags) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Lists the raised flags by name, e.g. `{overflow, inexact}`.
+impl fmt::Debug for ExceptionFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut separator = "";
+        f.write_str("{")?;
+        for &(flag, name) in &ExceptionFlags::NAMES {
+            if self.contains(flag) {
+                write!(f, "{}{}", separator, name)?;
+                separator = ", ";
+            }
+        }
+        f.write_str("}")
+    }
+}
+
+/// Dynamic state of an operation, in the spirit of simple-soft-float's
+/// `FPState` or the globals of Berkeley SoftFloat.
+///
+/// Backends read their rounding mode from here and translate it to whatever
+/// their library expects; those modelling the platform profile configure
+/// tininess detection and NaN propagation from it. Exception flags are
+/// sticky: operations add the flags they raise and nothing clears them but
+/// the owner of the environment.
+#[derive(Clone, Debug, Default)]
+pub struct FpEnv {
+    pub rounding_mode: RoundingMode,
+    pub flags: ExceptionFlags,
+    pub platform: Platform,
+}
+
+impl FpEnv {
+    pub fn new(rounding_mode: RoundingMode) -> FpEnv {
+        FpEnv {
+            rounding_mode,
+            flags: ExceptionFlags::NONE,
+            platform: Platform::default(),
+        }
+    }
+
+    /// Records `flags` as raised.
+    pub fn raise(&mut self, flags: ExceptionFlags) {
+        self.flags |= flags;
+    }
+}
@@ -0,0 +1,103 @@
+//! Exhaustive verification of binary16 arithmetic against MPFR.
+//!
+//! Binary16 has only 65,536 encodings, so every operand pair of a binary
+//! operation can be checked: about 4.3 billion cases per operation and
+//! rounding mode. The oracle is [`reference::apply`], which rounds MPFR's
+//! result once into binary16, subnormals and overflow included.
+
+use crate::backend::{Backend, Backends, DynBackend, Op, Rug};
+use crate::env::{FpEnv, RoundingMode};
+use crate::format::{Binary16, Format};
+use crate::reference;
+use std::collections::BTreeMap;
+use std::thread;
+
+/// The binary operations the checker covers.
+pub const OPS: [Op; 5] = [Op::Add, Op::Sub, Op::Mul, Op::Div, Op::Rem];
+
+/// Mismatching pairs kept as examples per operation, mode and backend.
+const EXAMPLES: usize = 16;
+
+/// Mismatches of one backend on one operation in one rounding mode.
+#[derive(Clone, Debug, Default)]
+pub struct Mismatches {
+    pub count: u64,
+    /// The first few mismatching operand pairs, with the backend's result
+    /// and the oracle's.
+    pub examples: Vec<([u16; 2], u16, u16)>,
+}
+
+impl Mismatches {
+    fn merge(&mut self, other: Mismatches) {
+        self.count += other.count;
+        self.examples.extend(other.examples);
+        self.examples.sort_unstable();
+        self.examples.truncate(EXAMPLES);
+    }
+}
+
+/// Mismatches by operation, rounding mode name and backend name.
+pub type Summary = BTreeMap<(Op, &'static str, &'static str), Mismatches>;
+
+fn check_range(
+    backends: &[DynBackend<Binary16>],
+    op: Op,
+    mode: RoundingMode,
+    first_operands: impl Iterator<Item = u16>,
+) -> Summary {
+    let mut summary = Summary::new();
+    for a in first_operands {
+        for b in 0..=u16::MAX {
+            let expected = reference::apply::<Binary16>(op, &[a, b], &mut FpEnv::new(mode));
+            for backend in backends {
+                let mut env = FpEnv::new(mode);
+                let result = match backend.apply(op, &[a, b], &mut env) {
+                    Some(result) => result,
+                    None => continue,
+                };
+                let nans = Binary16::is_nan(result) && Binary16::is_nan(expected);
+                if result != expected && !nans {
+                    let mismatches = summary.entry((op, mode.name(), backend.name)).or_default();
+                    mismatches.count += 1;
+                    if mismatches.examples.len() < EXAMPLES {
+                        mismatches.examples.push(([a, b], result, expected));
+                    }
+                }
+            }
+        }
+    }
+    summary
+}
+
+/// Checks every operand pair of each of `ops` in each of `modes` on `threads`
+/// threads, against every binary16 backend except rug, which serves as the
+/// oracle. NaN results match any NaN.
+pub fn run(ops: &[Op], modes: &[RoundingMode], threads: usize) -> Summary {
+    let mut summary = Summary::new();
+    for &op in ops {
+        for &mode in modes {
+            let parts: Vec<Summary> = thread::scope(|scope| {
+                let handles: Vec<_> = (0..threads)
+                    .map(|thread| {
+                        scope.spawn(move || {
+                            let backends: Vec<_> = Binary16::backends()
+                                .into_iter()
+                                .filter(|backend| backend.name != Rug::NAME)
+                                .filter(|backend| backend.rounding_modes.contains(&mode))
+                                .collect();
+                            let first_operands = (0..=u16::MAX).skip(thread).step_by(threads);
+                            check_range(&backends, op, mode, first_operands)
+                        })
+                    })
+                    .collect();
+                handles.into_iter().map(|h| h.join().unwrap()).collect()
+            });
+            for part in parts {
+                for (key, mismatches) in part {
+                    summary.entry(key).or_default().merge(mismatches);
+                }
+            }
+        }
+    }
+    summary
+}
@@ -0,0 +1,71 @@
+//! 8-bit floats: OCP E5M2 and E4M3FN.
+//!
+//! With 256 encodings a binary operation has only 65,536 operand pairs, so
+//! the benches here check every one of them against [`reference`] in every
+//! rounding mode before timing a pass over all of them, named
+//! `f8::<format>::<operation>::<backend>`.
+//!
+//! E5M2 follows IEEE 754 with binary16's exponent range. E4M3FN gives up
+//! the infinities for range: the largest exponent holds finite numbers up to
+//! 448, the only NaN of each sign has every other bit set, and results that
+//! would be infinite, after overflow or division by zero, are NaN instead.
+//! simple-soft-float describes E5M2 with custom `FloatProperties` but cannot
+//! express a format without infinities, so E4M3FN is left to rustc_apfloat.
+//!
+//! [`reference`]: crate::reference
+
+#[cfg(test)]
+macro_rules! exhaustive {
+    ($($op:ident: $variant:ident,)*; $format:ty, $backends:tt) => {
+        $(
+            mod $op {
+                exhaustive!($format, $variant, $backends);
+            }
+        )*
+    };
+    ($format:ty, $variant:ident, { $($name:ident: $backend:ty,)* }) => {
+        use crate::backend::*;
+        use crate::format::*;
+        use test::Bencher;
+
+        $(
+            #[bench]
+            fn $name(b: &mut Bencher) {
+                crate::bench::exhaustive::<$format, $backend>(b, Op::$variant);
+            }
+        )*
+    };
+}
+
+#[cfg(test)]
+mod e5m2 {
+    exhaustive! {
+        add: Add,
+        sub: Sub,
+        mul: Mul,
+        div: Div,
+        rem: Rem,
+        ;
+        Float8E5M2,
+        {
+            simple_soft_float: SimpleSoftFloat,
+            rustc_apfloat: RustcApfloat,
+        }
+    }
+}
+
+#[cfg(test)]
+mod e4m3fn {
+    exhaustive! {
+        add: Add,
+        sub: Sub,
+        mul: Mul,
+        div: Div,
+        rem: Rem,
+        ;
+        Float8E4M3FN,
+        {
+            rustc_apfloat: RustcApfloat,
+        }
+    }
+}
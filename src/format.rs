@@ -0,0 +1,199 @@
+//! Floating-point formats covered by the benchmarks, and the integer types
+//! they are converted to and from.
+
+use std::convert::TryFrom;
+use std::fmt::{Debug, Display};
+
+/// A floating-point format, identified by its bit-level encoding.
+pub trait Format {
+    /// Unsigned integer holding one encoded value.
+    type Bits: Copy + Eq + Debug + Into<u128> + TryFrom<u128>;
+
+    /// Short name used in benchmark paths and reports, e.g. `f32`.
+    const NAME: &'static str;
+
+    /// Width of the biased exponent field.
+    const EXPONENT_BITS: u32;
+
+    /// Width of the trailing significand field, i.e. the precision minus the
+    /// implicit leading bit.
+    const MANTISSA_BITS: u32;
+
+    /// Whether the significand field holds the leading integer bit
+    /// explicitly instead of implying it from the exponent, as the x87
+    /// format does. `MANTISSA_BITS` then counts it.
+    const EXPLICIT_INTEGER_BIT: bool = false;
+
+    /// Whether the format has infinities. Formats without them use the
+    /// largest exponent for finite numbers too, except for a single NaN
+    /// encoding per sign with every significand bit set.
+    const INFINITIES: bool = true;
+
+    /// Widens an encoding to `u128`.
+    fn widen(bits: Self::Bits) -> u128 {
+        bits.into()
+    }
+
+    /// Whether `bits` encodes a NaN, quiet or signaling.
+    fn is_nan(bits: Self::Bits) -> bool {
+        let bits = Self::widen(bits);
+        let exponent = bits >> Self::MANTISSA_BITS & Self::max_exponent();
+        let fraction_bits = Self::MANTISSA_BITS - Self::EXPLICIT_INTEGER_BIT as u32;
+        let fraction_max = (1 << fraction_bits) - 1;
+        let fraction = bits & fraction_max;
+        exponent == Self::max_exponent()
+            && if Self::INFINITIES {
+                fraction != 0
+            } else {
+                fraction == fraction_max
+            }
+    }
+
+    /// Narrows a `u128` back to an encoding of this format.
+    fn narrow(bits: u128) -> Self::Bits {
+        Self::Bits::try_from(bits)
+            .unwrap_or_else(|_| panic!("{:#x} does not fit in {}", bits, Self::NAME))
+    }
+
+    /// The biased exponent of infinities and NaNs, every exponent bit set.
+    fn max_exponent() -> u128 {
+        (1 << Self::EXPONENT_BITS) - 1
+    }
+
+    /// The exponent bias, i.e. the biased exponent of 1.0.
+    fn bias() -> u128 {
+        Self::max_exponent() >> 1
+    }
+
+    /// The sign bit, set in negative encodings.
+    fn sign_bit() -> u128 {
+        1 << (Self::EXPONENT_BITS + Self::MANTISSA_BITS)
+    }
+
+    /// The significand field with every bit set.
+    fn mantissa_mask() -> u128 {
+        (1 << Self::MANTISSA_BITS) - 1
+    }
+
+    /// The significand bit set in quiet NaNs and clear in signaling ones, the
+    /// highest below the integer bit.
+    fn quiet_bit() -> u128 {
+        1 << (Self::MANTISSA_BITS - 1 - Self::EXPLICIT_INTEGER_BIT as u32)
+    }
+
+    /// Assembles an encoding from its sign, biased exponent and significand
+    /// field.
+    fn encode(negative: bool, exponent: u128, mantissa: u128) -> Self::Bits {
+        let sign = if negative { Self::sign_bit() } else { 0 };
+        Self::narrow(sign | exponent << Self::MANTISSA_BITS | mantissa)
+    }
+
+    /// The infinity of the given sign, for formats that have them.
+    fn infinity(negative: bool) -> Self::Bits {
+        let integer_bit = (Self::EXPLICIT_INTEGER_BIT as u128) << (Self::MANTISSA_BITS - 1);
+        Self::encode(negative, Self::max_exponent(), integer_bit)
+    }
+}
+
+macro_rules! define_format {
+    (
+        $(#[$attr:meta])*
+        $format:ident,
+        $name:expr,
+        $bits:ty,
+        $exponent_bits:expr,
+        $mantissa_bits:expr
+    ) => {
+        $(#[$attr])*
+        pub struct $format;
+
+        impl Format for $format {
+            type Bits = $bits;
+            const NAME: &'static str = $name;
+            const EXPONENT_BITS: u32 = $exponent_bits;
+            const MANTISSA_BITS: u32 = $mantissa_bits;
+        }
+    };
+}
+
+define_format!(
+    /// IEEE 754 binary16 (half precision).
+    Binary16, "f16", u16, 5, 10
+);
+define_format!(
+    /// IEEE 754 binary32 (single precision).
+    Binary32, "f32", u32, 8, 23
+);
+define_format!(
+    /// IEEE 754 binary64 (double precision).
+    Binary64, "f64", u64, 11, 52
+);
+define_format!(
+    /// IEEE 754 binary128 (quadruple precision).
+    Binary128, "f128", u128, 15, 112
+);
+define_format!(
+    /// bfloat16: binary32's exponent range with 8 bits of precision.
+    BFloat16, "bf16", u16, 8, 7
+);
+define_format!(
+    /// OCP 8-bit float E5M2: binary16's exponent range with 3 bits of
+    /// precision, infinities and NaNs included.
+    Float8E5M2, "e5m2", u8, 5, 2
+);
+
+/// OCP 8-bit float E4M3FN: 4 exponent and 3 significand bits, finite
+/// numbers up to 448 and a single NaN per sign.
+pub struct Float8E4M3FN;
+
+impl Format for Float8E4M3FN {
+    type Bits = u8;
+    const NAME: &'static str = "e4m3fn";
+    const EXPONENT_BITS: u32 = 4;
+    const MANTISSA_BITS: u32 = 3;
+    const INFINITIES: bool = false;
+}
+
+/// The x87 80-bit double extended format: binary128's exponent range and a
+/// 64-bit significand whose leading bit is stored, in the low 80 bits.
+pub struct X87DoubleExtended;
+
+impl Format for X87DoubleExtended {
+    type Bits = u128;
+    const NAME: &'static str = "f80";
+    const EXPONENT_BITS: u32 = 15;
+    const MANTISSA_BITS: u32 = 64;
+    const EXPLICIT_INTEGER_BIT: bool = true;
+}
+
+/// An integer type converted to and from the formats.
+pub trait Int: Copy + Eq + Debug + Display {
+    /// Short name used in benchmark paths and reports, e.g. `i64`.
+    const NAME: &'static str;
+
+    /// Width in bits.
+    const BITS: u32;
+
+    const SIGNED: bool;
+}
+
+macro_rules! define_int {
+    ($($int:ident: $signed:expr,)*) => {
+        $(
+            impl Int for $int {
+                const NAME: &'static str = stringify!($int);
+                const BITS: u32 = $int::BITS;
+                const SIGNED: bool = $signed;
+            }
+        )*
+    };
+}
+
+define_int! {
+    i32: true,
+    u32: false,
+    i64: true,
+    u64: false,
+    i128: true,
+    u128: false,
+}
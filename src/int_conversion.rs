@@ -0,0 +1,95 @@
+//! What each backend returns when converting to an integer type fails.
+//!
+//! IEEE 754 has convertToInteger signal invalid when the operand is a NaN, an
+//! infinity or out of the destination's range, and leaves the result to the
+//! implementation. Hardware differs: x86 returns the "integer indefinite", the
+//! most negative signed or the largest unsigned value, whatever the operand,
+//! while RISC-V saturates and treats NaNs as positive. Rust's `as` saturates
+//! and turns NaNs into zero. This converts NaNs, infinities and finite values
+//! just beyond either end of the range, and records what each backend makes
+//! of them.
+
+use crate::backend::IntBackends;
+use crate::env::{ExceptionFlags, FpEnv};
+use crate::format::{Format, Int};
+
+/// One backend's result for one operand.
+#[derive(Clone, Debug)]
+pub struct Outcome<I: Int> {
+    /// The integer returned, or `None` where the backend returns none.
+    pub value: Option<I>,
+    /// Whether invalid was signaled, for backends that report flags.
+    pub invalid: Option<bool>,
+}
+
+/// An operand and every backend's outcome.
+pub struct Row<F: Format, I: Int> {
+    pub name: String,
+    pub bits: F::Bits,
+    /// One entry per backend, in [`IntBackends::int_backends`] order.
+    pub outcomes: Vec<Outcome<I>>,
+}
+
+/// `-2^exponent` or `2^exponent`, if the format holds it.
+fn power_of_two<F: Format>(negative: bool, exponent: u32) -> Option<F::Bits> {
+    let exponent = u128::from(exponent);
+    if exponent > F::bias() {
+        return None;
+    }
+    Some(F::encode(negative, exponent + F::bias(), 0))
+}
+
+/// NaNs, infinities, and the nearest powers of two outside the range of `I`
+/// that `F` holds: `2^BITS` or `2^(BITS - 1)` above it, and `-2^BITS` or
+/// `-1` below it.
+pub fn operands<F: Format, I: Int>() -> Vec<(String, F::Bits)> {
+    let all_ones = F::max_exponent();
+    let quiet = F::quiet_bit();
+    let mut operands = vec![
+        ("+qNaN".to_string(), F::encode(false, all_ones, quiet)),
+        ("-qNaN".to_string(), F::encode(true, all_ones, quiet)),
+        ("+sNaN".to_string(), F::encode(false, all_ones, 1)),
+        ("+inf".to_string(), F::infinity(false)),
+        ("-inf".to_string(), F::infinity(true)),
+    ];
+    let above = if I::SIGNED { I::BITS - 1 } else { I::BITS };
+    if let Some(bits) = power_of_two::<F>(false, above) {
+        operands.push((format!("2^{}", above), bits));
+    }
+    if !I::SIGNED {
+        operands.push(("-1".to_string(), power_of_two::<F>(true, 0).unwrap()));
+    } else if let Some(bits) = power_of_two::<F>(true, I::BITS) {
+        operands.push((format!("-2^{}", I::BITS), bits));
+    }
+    operands
+}
+
+/// Converts [`operands`] through every backend, rounding to nearest.
+pub fn run<F: IntBackends<I>, I: Int>() -> Vec<Row<F, I>> {
+    let backends = F::int_backends();
+    operands::<F, I>()
+        .into_iter()
+        .map(|(name, bits)| {
+            let outcomes = backends
+                .iter()
+                .map(|backend| {
+                    let mut env = FpEnv::default();
+                    let value = backend.to_int(bits, &mut env);
+                    Outcome {
+                        value,
+                        invalid: if backend.exception_flags {
+                            Some(env.flags.contains(ExceptionFlags::INVALID))
+                        } else {
+                            None
+                        },
+                    }
+                })
+                .collect();
+            Row {
+                name,
+                bits,
+                outcomes,
+            }
+        })
+        .collect()
+}
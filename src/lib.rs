@@ -1,870 +1,1377 @@
-#![feature(test)]
+#![feature(f16, f128, float_minimum_maximum, test)]
 extern crate test;
-use half;
-use rug::Float;
-use rustc_apfloat::{
-    ieee::{Double, Half, Quad, Single},
-    Round,
-};
-use const_soft_float::{
-    soft_f32::SoftF32,
-    soft_f64::SoftF64
-};
-use softfloat_pure;
-use simple_soft_float::{F128, F16, F32, F64};
-use softfloat_sys::*;
-use std::ops::{Add, Div, Mul};
-use test::Bencher;
 
-mod f16 {
-    use super::*;
-
-    #[bench]
-    fn add_simple_soft_float(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x1234);
-            let b = test::black_box(0x7654);
-            let a = F16::from_bits(a);
-            let b = F16::from_bits(b);
-            let d = a.add(&b, None, None);
-            assert_eq!(*d.bits(), 30292);
-            d
-        });
-    }
-
-    #[bench]
-    fn add_half(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x1234);
-            let b = test::black_box(0x7654);
-            let a = half::f16::from_bits(a);
-            let b = half::f16::from_bits(b);
-            let d = a + b;
-            assert_eq!(d.to_bits(), 30292);
-            d
-        });
-    }
-
-    #[bench]
-    fn add_softfloat_sys(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x1234);
-            let b = test::black_box(0x7654);
-            let a = float16_t { v: a };
-            let b = float16_t { v: b };
-            let d = unsafe { f16_add(a, b) };
-            assert_eq!(d.v, 30292);
-            d
-        });
-    }
-
-    #[bench]
-    fn add_rug(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x1234);
-            let b = test::black_box(0x7654);
-            let a = half::f16::from_bits(a);
-            let b = half::f16::from_bits(b);
-            let a = a.to_f32();
-            let b = b.to_f32();
-            let a = Float::with_val(11, a);
-            let b = Float::with_val(11, b);
-            let d = a.add(b);
-            let d = half::f16::from_f32(d.to_f32());
-            assert_eq!(d.to_bits(), 30292);
-            d
-        });
-    }
-
-    #[bench]
-    fn add_rustc_apfloat(b: &mut Bencher) {
-        use rustc_apfloat::Float;
-        b.iter(|| {
-            let a = test::black_box(0x1234);
-            let b = test::black_box(0x7654);
-            let a = Half::from_bits(a as u128);
-            let b = Half::from_bits(b as u128);
-            let d = a.add_r(b, Round::NearestTiesToEven);
-            assert_eq!(d.value.to_bits(), 30292);
-            d
-        });
-    }
-
-    #[bench]
-    fn mul_simple_soft_float(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x1234);
-            let b = test::black_box(0x7654);
-            let a = F16::from_bits(a);
-            let b = F16::from_bits(b);
-            let d = a.mul(&b, None, None);
-            assert_eq!(*d.bits(), 19688);
-            d
-        });
-    }
-
-    #[bench]
-    fn mul_half(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x1234);
-            let b = test::black_box(0x7654);
-            let a = half::f16::from_bits(a);
-            let b = half::f16::from_bits(b);
-            let d = a * b;
-            assert_eq!(d.to_bits(), 19688);
-            d
-        });
-    }
-
-    #[bench]
-    fn mul_softfloat_sys(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x1234);
-            let b = test::black_box(0x7654);
-            let a = float16_t { v: a };
-            let b = float16_t { v: b };
-            let d = unsafe { f16_mul(a, b) };
-            assert_eq!(d.v, 19688);
-            d
-        });
-    }
-
-    #[bench]
-    fn mul_rug(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x1234);
-            let b = test::black_box(0x7654);
-            let a = half::f16::from_bits(a);
-            let b = half::f16::from_bits(b);
-            let a = a.to_f32();
-            let b = b.to_f32();
-            let a = Float::with_val(11, a);
-            let b = Float::with_val(11, b);
-            let d = a.mul(b);
-            let d = half::f16::from_f32(d.to_f32());
-            assert_eq!(d.to_bits(), 19688);
-            d
-        });
-    }
-
-    #[bench]
-    fn mul_rustc_apfloat(b: &mut Bencher) {
-        use rustc_apfloat::Float;
-        b.iter(|| {
-            let a = test::black_box(0x1234);
-            let b = test::black_box(0x7654);
-            let a = Half::from_bits(a as u128);
-            let b = Half::from_bits(b as u128);
-            let d = a.mul_r(b, Round::NearestTiesToEven);
-            assert_eq!(d.value.to_bits(), 19688);
-            d
-        });
-    }
-
-    #[bench]
-    fn div_simple_soft_float(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x7654);
-            let b = test::black_box(0x1234);
-            let a = F16::from_bits(a);
-            let b = F16::from_bits(b);
-            let d = a.div(&b, None, None);
-            assert_eq!(*d.bits(), 31744);
-            d
-        });
-    }
-
-    #[bench]
-    fn div_half(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x7654);
-            let b = test::black_box(0x1234);
-            let a = half::f16::from_bits(a);
-            let b = half::f16::from_bits(b);
-            let d = a / b;
-            assert_eq!(d.to_bits(), 31744);
-            d
-        });
-    }
-
-    #[bench]
-    fn div_softfloat_sys(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x7654);
-            let b = test::black_box(0x1234);
-            let a = float16_t { v: a };
-            let b = float16_t { v: b };
-            let d = unsafe { f16_div(a, b) };
-            assert_eq!(d.v, 31744);
-            d
-        });
-    }
-
-    #[bench]
-    fn div_rug(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x7654);
-            let b = test::black_box(0x1234);
-            let a = half::f16::from_bits(a);
-            let b = half::f16::from_bits(b);
-            let a = a.to_f32();
-            let b = b.to_f32();
-            let a = Float::with_val(11, a);
-            let b = Float::with_val(11, b);
-            let d = a.div(b);
-            let d = half::f16::from_f32(d.to_f32());
-            assert_eq!(d.to_bits(), 31744);
-            d
-        });
-    }
-
-    #[bench]
-    fn div_rustc_apfloat(b: &mut Bencher) {
-        use rustc_apfloat::Float;
-        b.iter(|| {
-            let a = test::black_box(0x7654);
-            let b = test::black_box(0x1234);
-            let a = Half::from_bits(a as u128);
-            let b = Half::from_bits(b as u128);
-            let d = a.div_r(b, Round::NearestTiesToEven);
-            assert_eq!(d.value.to_bits(), 31744);
-            d
-        });
-    }
+#[macro_use]
+mod matrix;
+
+pub mod backend;
+pub mod bench;
+pub mod differential;
+pub mod double_rounding;
+pub mod edge_cases;
+pub mod env;
+pub mod exhaustive;
+pub mod f8;
+pub mod format;
+pub mod int_conversion;
+pub mod nan_propagation;
+pub mod platform;
+pub mod random;
+pub mod reference;
+
+// The class of each benchmark body, handed to `support!` so a library
+// lacking a whole class needs one arm. Other bodies are a class of their own.
+#[cfg_attr(not(test), allow(unused_macros))]
+macro_rules! op_class {
+    (parse, $($combination:tt)*) => { support!(@class text, $($combination)*); };
+    (format, $($combination:tt)*) => { support!(@class text, $($combination)*); };
+    (from_f32, $($combination:tt)*) => { support!(@class convert_f32, $($combination)*); };
+    (to_f32, $($combination:tt)*) => { support!(@class convert_f32, $($combination)*); };
+    (from_f64, $($combination:tt)*) => { support!(@class convert_f64, $($combination)*); };
+    (to_f64, $($combination:tt)*) => { support!(@class convert_f64, $($combination)*); };
+    (from_f128, $($combination:tt)*) => { support!(@class convert_f128, $($combination)*); };
+    (to_f128, $($combination:tt)*) => { support!(@class convert_f128, $($combination)*); };
+    (eq, $($combination:tt)*) => { support!(@class compare, $($combination)*); };
+    (eq_signaling, $($combination:tt)*) => { support!(@class compare, $($combination)*); };
+    (lt, $($combination:tt)*) => { support!(@class compare, $($combination)*); };
+    (lt_quiet, $($combination:tt)*) => { support!(@class compare, $($combination)*); };
+    (le, $($combination:tt)*) => { support!(@class compare, $($combination)*); };
+    (le_quiet, $($combination:tt)*) => { support!(@class compare, $($combination)*); };
+    (unordered, $($combination:tt)*) => { support!(@class compare, $($combination)*); };
+    (minimum, $($combination:tt)*) => { support!(@class minimum, $($combination)*); };
+    (maximum, $($combination:tt)*) => { support!(@class minimum, $($combination)*); };
+    (min_num, $($combination:tt)*) => { support!(@class min_num, $($combination)*); };
+    (max_num, $($combination:tt)*) => { support!(@class min_num, $($combination)*); };
+    ($op:ident, $($combination:tt)*) => { support!(@class $op, $($combination)*); };
 }
 
-mod f32 {
-    use super::*;
-
-    #[bench]
-    fn add_simple_soft_float(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667);
-            let b = test::black_box(0x76543210);
-            let a = F32::from_bits(a);
-            let b = F32::from_bits(b);
-            let d = a.add(&b, None, None);
-            assert_eq!(*d.bits(), 1985229328);
-            d
-        });
-    }
-
-    #[bench]
-    fn add_softfloat_sys(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667);
-            let b = test::black_box(0x76543210);
-            let a = float32_t { v: a };
-            let b = float32_t { v: b };
-            let d = unsafe { f32_add(a, b) };
-            assert_eq!(d.v, 1985229328);
-            d
-        });
-    }
-
-    #[bench]
-    fn add_softfloat_pure(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667);
-            let b = test::black_box(0x76543210);
-            let a = softfloat_pure::float32_t { v: a };
-            let b = softfloat_pure::float32_t { v: b };
-            let d = softfloat_pure::softfloat::f32_add(a, b, 0, 0);
-            assert_eq!(d.0.v, 1985229328);
-            d
-        });
-    }
-
-    #[bench]
-    fn add_const_soft_float(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667);
-            let b = test::black_box(0x76543210);
-            let a = SoftF32(f32::from_bits(a));
-            let b = SoftF32(f32::from_bits(b));
-            let d = a.add(b).to_bits();
-            assert_eq!(d, 1985229328);
-            d
-        });
-    }
-
-    #[bench]
-    fn add_rug(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667);
-            let b = test::black_box(0x76543210);
-            let a = Float::with_val(24, f32::from_bits(a));
-            let b = Float::with_val(24, f32::from_bits(b));
-            let d = a.add(b);
-            assert_eq!(d.to_f32().to_bits(), 1985229328);
-            d
-        });
-    }
-
-    #[bench]
-    fn add_rustc_apfloat(b: &mut Bencher) {
-        use rustc_apfloat::Float;
-        b.iter(|| {
-            let a = test::black_box(0x12345667);
-            let b = test::black_box(0x76543210);
-            let a = Single::from_bits(a as u128);
-            let b = Single::from_bits(b as u128);
-            let d = a.add_r(b, Round::NearestTiesToEven);
-            assert_eq!(d.value.to_bits(), 1985229328);
-            d
-        });
-    }
-
-    #[bench]
-    fn mul_simple_soft_float(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667);
-            let b = test::black_box(0x76543210);
-            let a = F32::from_bits(a);
-            let b = F32::from_bits(b);
-            let d = a.mul(&b, None, None);
-            assert_eq!(*d.bits(), 1226144465);
-            d
-        });
-    }
-
-    #[bench]
-    fn mul_softfloat_sys(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667);
-            let b = test::black_box(0x76543210);
-            let a = float32_t { v: a };
-            let b = float32_t { v: b };
-            let d = unsafe { f32_mul(a, b) };
-            assert_eq!(d.v, 1226144465);
-            d
-        });
-    }
-
-    #[bench]
-    fn mul_softfloat_pure(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667);
-            let b = test::black_box(0x76543210);
-            let a = softfloat_pure::float32_t { v: a };
-            let b = softfloat_pure::float32_t { v: b };
-            let d = softfloat_pure::softfloat::f32_mul(a, b, 0, 0);
-            assert_eq!(d.0.v, 1226144465);
-            d
-        });
-    }
-
-    #[bench]
-    fn mul_const_soft_float(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667);
-            let b = test::black_box(0x76543210);
-            let a = SoftF32(f32::from_bits(a));
-            let b = SoftF32(f32::from_bits(b));
-            let d = a.mul(b).to_bits();
-            assert_eq!(d, 1226144465);
-            d
-        });
-    }
-
-    #[bench]
-    fn mul_rug(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667);
-            let b = test::black_box(0x76543210);
-            let a = Float::with_val(24, f32::from_bits(a));
-            let b = Float::with_val(24, f32::from_bits(b));
-            let d = a.mul(b);
-            assert_eq!(d.to_f32().to_bits(), 1226144465);
-            d
-        });
-    }
-
-    #[bench]
-    fn mul_rustc_apfloat(b: &mut Bencher) {
-        use rustc_apfloat::Float;
-        b.iter(|| {
-            let a = test::black_box(0x12345667);
-            let b = test::black_box(0x76543210);
-            let a = Single::from_bits(a as u128);
-            let b = Single::from_bits(b as u128);
-            let d = a.mul_r(b, Round::NearestTiesToEven);
-            assert_eq!(d.value.to_bits(), 1226144465);
-            d
-        });
-    }
-
-    #[bench]
-    fn div_simple_soft_float(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x76543210);
-            let b = test::black_box(0x12345667);
-            let a = F32::from_bits(a);
-            let b = F32::from_bits(b);
-            let d = a.div(&b, None, None);
-            assert_eq!(*d.bits(), 2139095040);
-            d
-        });
-    }
-
-    #[bench]
-    fn div_softfloat_sys(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x76543210);
-            let b = test::black_box(0x12345667);
-            let a = float32_t { v: a };
-            let b = float32_t { v: b };
-            let d = unsafe { f32_div(a, b) };
-            assert_eq!(d.v, 2139095040);
-            d
-        });
-    }
-
-    #[bench]
-    fn div_softfloat_pure(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x76543210);
-            let b = test::black_box(0x12345667);
-            let a = softfloat_pure::float32_t { v: a };
-            let b = softfloat_pure::float32_t { v: b };
-            let d = softfloat_pure::softfloat::f32_div(a, b, 0, 0);
-            assert_eq!(d.0.v, 2139095040);
-            d
-        });
-    }
-
-    #[bench]
-    fn div_const_soft_float(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x76543210);
-            let b = test::black_box(0x12345667);
-            let a = SoftF32(f32::from_bits(a));
-            let b = SoftF32(f32::from_bits(b));
-            let d = a.div(b).to_bits();
-            assert_eq!(d, 2139095040);
-            d
-        });
-    }
-
-    #[bench]
-    fn div_rug(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x76543210);
-            let b = test::black_box(0x12345667);
-            let a = Float::with_val(24, f32::from_bits(a));
-            let b = Float::with_val(24, f32::from_bits(b));
-            let d = a.div(b);
-            assert_eq!(d.to_f32().to_bits(), 2139095040);
-            d
-        });
-    }
-
-    #[bench]
-    fn div_rustc_apfloat(b: &mut Bencher) {
-        use rustc_apfloat::Float;
-        b.iter(|| {
-            let a = test::black_box(0x76543210);
-            let b = test::black_box(0x12345667);
-            let a = Single::from_bits(a as u128);
-            let b = Single::from_bits(b as u128);
-            let d = a.div_r(b, Round::NearestTiesToEven);
-            assert_eq!(d.value.to_bits(), 2139095040);
-            d
-        });
-    }
+// Library, format, operation and rounding mode combinations without an
+// implementation, or with one known to give a wrong result. Arms are tried in
+// order; anything not listed is benchmarked.
+#[cfg_attr(not(test), allow(unused_macros))]
+macro_rules! support {
+    // Whole classes of operations, as grouped by `op_class!`.
+    (@class sqrt, compiler_builtins, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class fma, compiler_builtins, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class rem, compiler_builtins, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class fma, const_soft_float, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class rem, const_soft_float, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class rem, half, f16, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class rem, native, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    // Conversions between the IEEE formats.
+    (@class convert_f64, softfloat_pure, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class convert_f128, softfloat_pure, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class convert_f64, const_soft_float, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class convert_f128, const_soft_float, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class convert_f128, half, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class convert_f128, rug, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    // Conversions to and from text.
+    (@class text, simple_soft_float, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class text, softfloat_sys, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class text, softfloat_pure, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class text, const_soft_float, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class text, compiler_builtins, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class text, native, f16, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class text, native, f128, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    // Comparisons.
+    (@class compare, softfloat_pure, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    // Rounding to integral values.
+    (@class round_to_integral, softfloat_pure, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class round_to_integral, const_soft_float, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class round_to_integral, compiler_builtins, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    // Minimum and maximum.
+    (@class minimum, softfloat_sys, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class minimum, simple_soft_float, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class minimum, softfloat_pure, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class minimum, const_soft_float, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class minimum, compiler_builtins, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class minimum, half, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class minimum, rug, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class min_num, softfloat_sys, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class min_num, simple_soft_float, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class min_num, softfloat_pure, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class min_num, const_soft_float, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class min_num, compiler_builtins, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (@class $class:ident, $($combination:tt)*) => { support!($($combination)*); };
+    (half, f32, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (half, f64, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (half, f128, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (softfloat_sys, bf16, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (softfloat_pure, f16, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (softfloat_pure, f128, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (const_soft_float, f16, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (softfloat_pure, bf16, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (const_soft_float, f128, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (const_soft_float, bf16, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (rug, f128, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (simple_soft_float, f80, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (softfloat_pure, f80, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (const_soft_float, f80, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (half, f80, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (rug, f80, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (softfloat_sys, f80, fma, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (compiler_builtins, f16, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (compiler_builtins, bf16, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (compiler_builtins, f80, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (native, bf16, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (native, f80, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (rustc_apfloat, $format:ident, sqrt, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (half, f16, fma, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (half, bf16, fma, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    // Text conversions missing from libraries that have the rest of the class.
+    (native, $format:ident, parse_hex, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (half, $format:ident, parse_hex, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    // half parses through f32, which rounds a literal just above a midpoint
+    // of the format onto it, and then to even.
+    (half, $format:ident, parse_long, rne, $cb:ident $args:tt) => { $cb!(incorrect $args); };
+    // Rounding modes, once the combination itself is known to exist.
+    (half, $format:ident, $op:ident, rne, $cb:ident $args:tt) => { $cb!(supported $args); };
+    (half, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (const_soft_float, $format:ident, $op:ident, rne, $cb:ident $args:tt) => { $cb!(supported $args); };
+    (const_soft_float, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (compiler_builtins, $format:ident, $op:ident, rne, $cb:ident $args:tt) => { $cb!(supported $args); };
+    (compiler_builtins, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (native, $format:ident, $op:ident, rne, $cb:ident $args:tt) => { $cb!(supported $args); };
+    (native, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (rug, $format:ident, $op:ident, rna, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    ($backend:ident, $format:ident, $op:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(supported $args); };
 }
 
-mod f64 {
-    use super::*;
-
-    #[bench]
-    fn add_simple_soft_float(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667ffffffff);
-            let b = test::black_box(0x76543210aaaaaaaa);
-            let a = F64::from_bits(a);
-            let b = F64::from_bits(b);
-            let d = a.add(&b, None, None);
-            assert_eq!(*d.bits(), 8526495041683368618u64);
-            d
-        });
-    }
-
-    #[bench]
-    fn add_softfloat_sys(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667ffffffff);
-            let b = test::black_box(0x76543210aaaaaaaa);
-            let a = float64_t { v: a };
-            let b = float64_t { v: b };
-            let d = unsafe { f64_add(a, b) };
-            assert_eq!(d.v, 8526495041683368618u64);
-            d
-        });
-    }
-
-    #[bench]
-    fn add_softfloat_pure(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667ffffffff);
-            let b = test::black_box(0x76543210aaaaaaaa);
-            let a = softfloat_pure::float64_t { v: a };
-            let b = softfloat_pure::float64_t { v: b };
-            let d = softfloat_pure::softfloat::f64_add(a, b, 0, 0);
-            assert_eq!(d.0.v, 8526495041683368618u64);
-            d
-        });
-    }
-
-    #[bench]
-    fn add_const_soft_float(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667ffffffff);
-            let b = test::black_box(0x76543210aaaaaaaa);
-            let a = SoftF64(f64::from_bits(a));
-            let b = SoftF64(f64::from_bits(b));
-            let d = a.add(b).to_bits();
-            assert_eq!(d, 8526495041683368618u64);
-            d
-        });
-    }
-
-    #[bench]
-    fn add_rug(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667ffffffff);
-            let b = test::black_box(0x76543210aaaaaaaa);
-            let a = Float::with_val(53, f64::from_bits(a));
-            let b = Float::with_val(53, f64::from_bits(b));
-            let d = a.add(b);
-            assert_eq!(d.to_f64().to_bits(), 8526495041683368618u64);
-            d
-        });
-    }
-
-    #[bench]
-    fn add_rustc_apfloat(b: &mut Bencher) {
-        use rustc_apfloat::Float;
-        b.iter(|| {
-            let a = test::black_box(0x12345667ffffffffu64);
-            let b = test::black_box(0x76543210aaaaaaaau64);
-            let a = Double::from_bits(a as u128);
-            let b = Double::from_bits(b as u128);
-            let d = a.add_r(b, Round::NearestTiesToEven);
-            assert_eq!(d.value.to_bits(), 8526495041683368618u128);
-            d
-        });
-    }
-
-    #[bench]
-    fn mul_simple_soft_float(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667ffffffff);
-            let b = test::black_box(0x76543210aaaaaaaa);
-            let a = F64::from_bits(a);
-            let b = F64::from_bits(b);
-            let d = a.mul(&b, None, None);
-            assert_eq!(*d.bits(), 5231401168203612158u64);
-            d
-        });
-    }
-
-    #[bench]
-    fn mul_softfloat_sys(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667ffffffff);
-            let b = test::black_box(0x76543210aaaaaaaa);
-            let a = float64_t { v: a };
-            let b = float64_t { v: b };
-            let d = unsafe { f64_mul(a, b) };
-            assert_eq!(d.v, 5231401168203612158u64);
-            d
-        });
-    }
-
-    #[bench]
-    fn mul_softfloat_pure(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667ffffffff);
-            let b = test::black_box(0x76543210aaaaaaaa);
-            let a = softfloat_pure::float64_t { v: a };
-            let b = softfloat_pure::float64_t { v: b };
-            let d = softfloat_pure::softfloat::f64_mul(a, b, 0, 0);
-            assert_eq!(d.0.v, 5231401168203612158u64);
-            d
-        });
-    }
-
-    #[bench]
-    fn mul_const_soft_float(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667ffffffff);
-            let b = test::black_box(0x76543210aaaaaaaa);
-            let a = SoftF64(f64::from_bits(a));
-            let b = SoftF64(f64::from_bits(b));
-            let d = a.mul(b).to_bits();
-            assert_eq!(d, 5231401168203612158u64);
-            d
-        });
-    }
-
-    #[bench]
-    fn mul_rug(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667ffffffff);
-            let b = test::black_box(0x76543210aaaaaaaa);
-            let a = Float::with_val(53, f64::from_bits(a));
-            let b = Float::with_val(53, f64::from_bits(b));
-            let d = a.mul(b);
-            assert_eq!(d.to_f64().to_bits(), 5231401168203612158u64);
-            d
-        });
-    }
-
-    #[bench]
-    fn mul_rustc_apfloat(b: &mut Bencher) {
-        use rustc_apfloat::Float;
-        b.iter(|| {
-            let a = test::black_box(0x12345667ffffffffu64);
-            let b = test::black_box(0x76543210aaaaaaaau64);
-            let a = Double::from_bits(a as u128);
-            let b = Double::from_bits(b as u128);
-            let d = a.mul_r(b, Round::NearestTiesToEven);
-            assert_eq!(d.value.to_bits(), 5231401168203612158u128);
-            d
-        });
-    }
-
-    #[bench]
-    fn div_simple_soft_float(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x76543210aaaaaaaa);
-            let b = test::black_box(0x12345667ffffffff);
-            let a = F64::from_bits(a);
-            let b = F64::from_bits(b);
-            let d = a.div(&b, None, None);
-            assert_eq!(*d.bits(), 9218868437227405312u64);
-            d
-        });
-    }
+// Library, format and platform profile combinations the library reproduces
+// exactly, i.e. those in its `SoftFloatBackend::PLATFORMS`; the format must
+// also be supported. Everything else is reported as unsupported.
+#[cfg_attr(not(test), allow(unused_macros))]
+macro_rules! platform_support {
+    (simple_soft_float, $format:ident, $platform:ident, $cb:ident $args:tt) => {
+        support!(simple_soft_float, $format, add, rne, $cb $args);
+    };
+    (softfloat_sys, $format:ident, $platform:ident, $cb:ident $args:tt) => {
+        softfloat_sys_platform!($format, $platform, $cb $args);
+    };
+    ($backend:ident, $format:ident, $platform:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+}
 
-    #[bench]
-    fn div_softfloat_sys(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x76543210aaaaaaaa);
-            let b = test::black_box(0x12345667ffffffff);
-            let a = float64_t { v: a };
-            let b = float64_t { v: b };
-            let d = unsafe { f64_div(a, b) };
-            assert_eq!(d.v, 9218868437227405312u64);
-            d
-        });
-    }
+// SoftFloat's NaN propagation is chosen when it is built.
+#[cfg(not(feature = "x86"))]
+#[cfg_attr(not(test), allow(unused_macros))]
+macro_rules! softfloat_sys_platform {
+    ($format:ident, riscv, $cb:ident $args:tt) => { support!(softfloat_sys, $format, add, rne, $cb $args); };
+    ($format:ident, $platform:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+}
 
-    #[bench]
-    fn div_softfloat_pure(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x76543210aaaaaaaa);
-            let b = test::black_box(0x12345667ffffffff);
-            let a = softfloat_pure::float64_t { v: a };
-            let b = softfloat_pure::float64_t { v: b };
-            let d = softfloat_pure::softfloat::f64_div(a, b, 0, 0);
-            assert_eq!(d.0.v, 9218868437227405312u64);
-            d
-        });
-    }
+#[cfg(feature = "x86")]
+#[cfg_attr(not(test), allow(unused_macros))]
+macro_rules! softfloat_sys_platform {
+    ($format:ident, x86, $cb:ident $args:tt) => { support!(softfloat_sys, $format, add, rne, $cb $args); };
+    ($format:ident, $platform:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+}
 
-    #[bench]
-    fn div_const_soft_float(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x76543210aaaaaaaa);
-            let b = test::black_box(0x12345667ffffffff);
-            let a = SoftF64(f64::from_bits(a));
-            let b = SoftF64(f64::from_bits(b));
-            let d = a.div(b).to_bits();
-            assert_eq!(d, 9218868437227405312u64);
-            d
-        });
-    }
+// Library, format, integer type and direction combinations without an
+// implementation, as for `support!`.
+#[cfg_attr(not(test), allow(unused_macros))]
+macro_rules! int_support {
+    (softfloat_sys, $format:ident, i128, $dir:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (softfloat_sys, $format:ident, u128, $dir:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (rug, f128, $int:ident, $dir:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (rug, $format:ident, $int:ident, $dir:ident, rna, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (native, $format:ident, $int:ident, from, rne, $cb:ident $args:tt) => { $cb!(supported $args); };
+    (native, $format:ident, $int:ident, from, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+    (simple_soft_float, $format:ident, $int:ident, $dir:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(supported $args); };
+    (softfloat_sys, $format:ident, $int:ident, $dir:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(supported $args); };
+    (rug, $format:ident, $int:ident, $dir:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(supported $args); };
+    (rustc_apfloat, $format:ident, $int:ident, $dir:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(supported $args); };
+    (native, $format:ident, $int:ident, $dir:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(supported $args); };
+    ($backend:ident, $format:ident, $int:ident, $dir:ident, $mode:ident, $cb:ident $args:tt) => { $cb!(unsupported $args); };
+}
 
-    #[bench]
-    fn div_rug(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x76543210aaaaaaaa);
-            let b = test::black_box(0x12345667ffffffff);
-            let a = Float::with_val(53, f64::from_bits(a));
-            let b = Float::with_val(53, f64::from_bits(b));
-            let d = a.div(b);
-            assert_eq!(d.to_f64().to_bits(), 9218868437227405312u64);
-            d
-        });
+// The fma operands add the negated, rounded product of the first two, so the
+// fused result is the product's rounding error while mul-then-add gives zero.
+// The `rem_gap` operands have exponents far apart, which most libraries
+// handle by long division one chunk of quotient bits at a time.
+// Formatting does not round, so `format` rows only run in rne, and give the
+// shortest literal rather than what every library prints.
+bench_matrix! {
+    formats {
+        f16: Binary16 {
+            add(0x1234, 0x7654) => {
+                rne: 0x7654,
+                rtz: 0x7654,
+                rup: 0x7655,
+                rdn: 0x7654,
+                rna: 0x7654,
+            } raises(inexact),
+            sub(0x1234, 0x7654) => {
+                rne: 0xf654,
+                rtz: 0xf653,
+                rup: 0xf653,
+                rdn: 0xf654,
+                rna: 0xf654,
+            } raises(inexact),
+            mul(0x1234, 0x7654) => {
+                rne: 0x4ce8,
+                rtz: 0x4ce8,
+                rup: 0x4ce9,
+                rdn: 0x4ce8,
+                rna: 0x4ce8,
+            } raises(inexact),
+            div(0x7654, 0x1234) => {
+                rne: 0x7c00,
+                rtz: 0x7bff,
+                rup: 0x7c00,
+                rdn: 0x7bff,
+                rna: 0x7c00,
+            } raises(overflow, inexact),
+            sqrt(0x1234) => {
+                rne: 0x270b,
+                rtz: 0x270b,
+                rup: 0x270c,
+                rdn: 0x270b,
+                rna: 0x270b,
+            } raises(inexact),
+            fma(0x1234, 0x7654, 0xcce8) => 0x1840,
+            rem(0x7654, 0x6543) => 0x5d1c,
+            rem_gap = rem(0x7654, 0x1234) => 0x8800,
+            // Conversions from and to the wider formats: a tie, an overflow
+            // and a subnormal result when narrowing, a subnormal when
+            // widening.
+            from_f32(0x3f801000) => {
+                rne: 0x3c00,
+                rtz: 0x3c00,
+                rup: 0x3c01,
+                rdn: 0x3c00,
+                rna: 0x3c01,
+            } raises(inexact),
+            from_f32_overflow = from_f32(0x47c00000) => {
+                rne: 0x7c00,
+                rtz: 0x7bff,
+                rup: 0x7c00,
+                rdn: 0x7bff,
+                rna: 0x7c00,
+            } raises(overflow, inexact),
+            from_f32_subnormal = from_f32(0x36266666) => {
+                rne: 0x002a,
+                rtz: 0x0029,
+                rup: 0x002a,
+                rdn: 0x0029,
+                rna: 0x002a,
+            } raises(underflow, inexact),
+            from_f64(0x3ff0020000000000) => {
+                rne: 0x3c00,
+                rtz: 0x3c00,
+                rup: 0x3c01,
+                rdn: 0x3c00,
+                rna: 0x3c01,
+            } raises(inexact),
+            from_f64_overflow = from_f64(0x40f8000000000000) => {
+                rne: 0x7c00,
+                rtz: 0x7bff,
+                rup: 0x7c00,
+                rdn: 0x7bff,
+                rna: 0x7c00,
+            } raises(overflow, inexact),
+            from_f64_subnormal = from_f64(0x3ec4cccccccccccd) => {
+                rne: 0x002a,
+                rtz: 0x0029,
+                rup: 0x002a,
+                rdn: 0x0029,
+                rna: 0x002a,
+            } raises(underflow, inexact),
+            from_f128(0x3fff0020000000000000000000000000) => {
+                rne: 0x3c00,
+                rtz: 0x3c00,
+                rup: 0x3c01,
+                rdn: 0x3c00,
+                rna: 0x3c01,
+            } raises(inexact),
+            from_f128_overflow = from_f128(0x400f8000000000000000000000000000) => {
+                rne: 0x7c00,
+                rtz: 0x7bff,
+                rup: 0x7c00,
+                rdn: 0x7bff,
+                rna: 0x7c00,
+            } raises(overflow, inexact),
+            from_f128_subnormal = from_f128(0x3fec4ccccccccccccccccccccccccccd) => {
+                rne: 0x002a,
+                rtz: 0x0029,
+                rup: 0x002a,
+                rdn: 0x0029,
+                rna: 0x002a,
+            } raises(underflow, inexact),
+            to_f32(0x0234) => 0x380d0000,
+            to_f64(0x0234) => 0x3f01a00000000000,
+            to_f128(0x0234) => 0x3ff01a00000000000000000000000000,
+            parse("0.1") => {
+                rne: 0x2e66,
+                rtz: 0x2e66,
+                rup: 0x2e67,
+                rdn: 0x2e66,
+                rna: 0x2e66,
+            } raises(inexact),
+            // Just above 1 + 2^-11, the midpoint between 1 and the next f16.
+            parse_long = parse("1.00048828125000000000000000000000000000000000000000000000001") => {
+                rne: 0x3c01,
+                rtz: 0x3c00,
+                rup: 0x3c01,
+                rdn: 0x3c00,
+                rna: 0x3c01,
+            } raises(inexact),
+            // pi.
+            parse_hex = parse("0x3.243f6a8885a308d313198a2e03707344ap0") => {
+                rne: 0x4248,
+                rtz: 0x4248,
+                rup: 0x4249,
+                rdn: 0x4248,
+                rna: 0x4248,
+            } raises(inexact),
+            format(0x2e66) => { rne: "0.1" },
+            format_subnormal = format(0x0001) => { rne: "6e-8" },
+            eq(0x0000, 0x8000) => { rne: true },
+            eq_nan = eq(0x7e00, 0x7e00) => { rne: false },
+            eq_signaling(0x7e00, 0x3c00) => { rne: false } raises(invalid),
+            lt(0xc000, 0x3c00) => { rne: true },
+            lt_nan = lt(0x3c00, 0x7e00) => { rne: false } raises(invalid),
+            lt_quiet(0x3c00, 0x7e00) => { rne: false },
+            le(0x8000, 0x0000) => { rne: true },
+            le_quiet(0x7c01, 0x3c00) => { rne: false } raises(invalid),
+            unordered(0x3c00, 0x7e00) => { rne: true },
+            unordered_snan = unordered(0x7c01, 0x3c00) => { rne: true } raises(invalid),
+            round_to_integral(0x4100) => {
+                rne: 0x4000,
+                rtz: 0x4000,
+                rup: 0x4200,
+                rdn: 0x4000,
+                rna: 0x4200,
+            },
+            round_to_integral_neg = round_to_integral(0xb800) => {
+                rne: 0x8000,
+                rtz: 0x8000,
+                rup: 0x8000,
+                rdn: 0xbc00,
+                rna: 0xbc00,
+            },
+            minimum(0x0000, 0x8000) => { rne: 0x8000 },
+            minimum_nan = minimum(0x3c00, 0x7e00) => { rne: 0x7e00 },
+            maximum(0x8000, 0x0000) => { rne: 0x0000 },
+            maximum_nan = maximum(0x7e00, 0x3c00) => { rne: 0x7e00 },
+            min_num(0xc000, 0x3c00) => { rne: 0xc000 },
+            min_num_nan = min_num(0x3c00, 0x7e00) => { rne: 0x3c00 },
+            min_num_nan_first = min_num(0x7e00, 0x3c00) => { rne: 0x3c00 },
+            max_num(0xc000, 0x3c00) => { rne: 0x3c00 },
+            max_num_nan = max_num(0x7e00, 0x3c00) => { rne: 0x3c00 },
+        }
+        f32: Binary32 {
+            add(0x12345667, 0x76543210) => {
+                rne: 0x76543210,
+                rtz: 0x76543210,
+                rup: 0x76543211,
+                rdn: 0x76543210,
+                rna: 0x76543210,
+            } raises(inexact),
+            sub(0x12345667, 0x76543210) => {
+                rne: 0xf6543210,
+                rtz: 0xf654320f,
+                rup: 0xf654320f,
+                rdn: 0xf6543210,
+                rna: 0xf6543210,
+            } raises(inexact),
+            mul(0x12345667, 0x76543210) => {
+                rne: 0x49157ad1,
+                rtz: 0x49157ad1,
+                rup: 0x49157ad2,
+                rdn: 0x49157ad1,
+                rna: 0x49157ad1,
+            } raises(inexact),
+            div(0x76543210, 0x12345667) => {
+                rne: 0x7f800000,
+                rtz: 0x7f7fffff,
+                rup: 0x7f800000,
+                rdn: 0x7f7fffff,
+                rna: 0x7f800000,
+            } raises(overflow, inexact),
+            sqrt(0x12345667) => {
+                rne: 0x28d6dd1a,
+                rtz: 0x28d6dd1a,
+                rup: 0x28d6dd1b,
+                rdn: 0x28d6dd1a,
+                rna: 0x28d6dd1a,
+            } raises(inexact),
+            fma(0x12345667, 0x76543210, 0xc9157ad1) => 0x3ce308e0,
+            rem(0x76543210, 0x75432100) => 0x74888880,
+            rem_gap = rem(0x76543210, 0x12345667) => 0x1006dcb0,
+            from_f64(0x3ff0000010000000) => {
+                rne: 0x3f800000,
+                rtz: 0x3f800000,
+                rup: 0x3f800001,
+                rdn: 0x3f800000,
+                rna: 0x3f800001,
+            } raises(inexact),
+            from_f64_overflow = from_f64(0x47f8000000000000) => {
+                rne: 0x7f800000,
+                rtz: 0x7f7fffff,
+                rup: 0x7f800000,
+                rdn: 0x7f7fffff,
+                rna: 0x7f800000,
+            } raises(overflow, inexact),
+            from_f64_subnormal = from_f64(0x37c4cccccccccccd) => {
+                rne: 0x00053333,
+                rtz: 0x00053333,
+                rup: 0x00053334,
+                rdn: 0x00053333,
+                rna: 0x00053333,
+            } raises(underflow, inexact),
+            from_f128(0x3fff0000010000000000000000000000) => {
+                rne: 0x3f800000,
+                rtz: 0x3f800000,
+                rup: 0x3f800001,
+                rdn: 0x3f800000,
+                rna: 0x3f800001,
+            } raises(inexact),
+            from_f128_overflow = from_f128(0x407f8000000000000000000000000000) => {
+                rne: 0x7f800000,
+                rtz: 0x7f7fffff,
+                rup: 0x7f800000,
+                rdn: 0x7f7fffff,
+                rna: 0x7f800000,
+            } raises(overflow, inexact),
+            from_f128_subnormal = from_f128(0x3f7c4ccccccccccccccccccccccccccd) => {
+                rne: 0x00053333,
+                rtz: 0x00053333,
+                rup: 0x00053334,
+                rdn: 0x00053333,
+                rna: 0x00053333,
+            } raises(underflow, inexact),
+            to_f64(0x00234567) => 0x37f1a2b380000000,
+            to_f128(0x00234567) => 0x3f7f1a2b380000000000000000000000,
+            parse("0.1") => {
+                rne: 0x3dcccccd,
+                rtz: 0x3dcccccc,
+                rup: 0x3dcccccd,
+                rdn: 0x3dcccccc,
+                rna: 0x3dcccccd,
+            } raises(inexact),
+            // Just above 1 + 2^-24, the midpoint between 1 and the next f32.
+            parse_long = parse("1.00000005960464477539062500000000000000000000000000000000001") => {
+                rne: 0x3f800001,
+                rtz: 0x3f800000,
+                rup: 0x3f800001,
+                rdn: 0x3f800000,
+                rna: 0x3f800001,
+            } raises(inexact),
+            // pi.
+            parse_hex = parse("0x3.243f6a8885a308d313198a2e03707344ap0") => {
+                rne: 0x40490fdb,
+                rtz: 0x40490fda,
+                rup: 0x40490fdb,
+                rdn: 0x40490fda,
+                rna: 0x40490fdb,
+            } raises(inexact),
+            format(0x3dcccccd) => { rne: "0.1" },
+            format_subnormal = format(0x00000001) => { rne: "1e-45" },
+            eq(0x00000000, 0x80000000) => { rne: true },
+            eq_nan = eq(0x7fc00000, 0x7fc00000) => { rne: false },
+            eq_signaling(0x7fc00000, 0x3f800000) => { rne: false } raises(invalid),
+            lt(0xc0000000, 0x3f800000) => { rne: true },
+            lt_nan = lt(0x3f800000, 0x7fc00000) => { rne: false } raises(invalid),
+            lt_quiet(0x3f800000, 0x7fc00000) => { rne: false },
+            le(0x80000000, 0x00000000) => { rne: true },
+            le_quiet(0x7f800001, 0x3f800000) => { rne: false } raises(invalid),
+            unordered(0x3f800000, 0x7fc00000) => { rne: true },
+            unordered_snan = unordered(0x7f800001, 0x3f800000) => { rne: true } raises(invalid),
+            round_to_integral(0x40200000) => {
+                rne: 0x40000000,
+                rtz: 0x40000000,
+                rup: 0x40400000,
+                rdn: 0x40000000,
+                rna: 0x40400000,
+            },
+            round_to_integral_neg = round_to_integral(0xbf000000) => {
+                rne: 0x80000000,
+                rtz: 0x80000000,
+                rup: 0x80000000,
+                rdn: 0xbf800000,
+                rna: 0xbf800000,
+            },
+            minimum(0x00000000, 0x80000000) => { rne: 0x80000000 },
+            minimum_nan = minimum(0x3f800000, 0x7fc00000) => { rne: 0x7fc00000 },
+            maximum(0x80000000, 0x00000000) => { rne: 0x00000000 },
+            maximum_nan = maximum(0x7fc00000, 0x3f800000) => { rne: 0x7fc00000 },
+            min_num(0xc0000000, 0x3f800000) => { rne: 0xc0000000 },
+            min_num_nan = min_num(0x3f800000, 0x7fc00000) => { rne: 0x3f800000 },
+            min_num_nan_first = min_num(0x7fc00000, 0x3f800000) => { rne: 0x3f800000 },
+            max_num(0xc0000000, 0x3f800000) => { rne: 0x3f800000 },
+            max_num_nan = max_num(0x7fc00000, 0x3f800000) => { rne: 0x3f800000 },
+        }
+        f64: Binary64 {
+            add(0x12345667ffffffff, 0x76543210aaaaaaaa) => {
+                rne: 0x76543210aaaaaaaa,
+                rtz: 0x76543210aaaaaaaa,
+                rup: 0x76543210aaaaaaab,
+                rdn: 0x76543210aaaaaaaa,
+                rna: 0x76543210aaaaaaaa,
+            } raises(inexact),
+            sub(0x12345667ffffffff, 0x76543210aaaaaaaa) => {
+                rne: 0xf6543210aaaaaaaa,
+                rtz: 0xf6543210aaaaaaa9,
+                rup: 0xf6543210aaaaaaa9,
+                rdn: 0xf6543210aaaaaaaa,
+                rna: 0xf6543210aaaaaaaa,
+            } raises(inexact),
+            mul(0x12345667ffffffff, 0x76543210aaaaaaaa) => {
+                rne: 0x4899aba53456fffe,
+                rtz: 0x4899aba53456fffd,
+                rup: 0x4899aba53456fffe,
+                rdn: 0x4899aba53456fffd,
+                rna: 0x4899aba53456fffe,
+            } raises(inexact),
+            div(0x76543210aaaaaaaa, 0x12345667ffffffff) => {
+                rne: 0x7ff0000000000000,
+                rtz: 0x7fefffffffffffff,
+                rup: 0x7ff0000000000000,
+                rdn: 0x7fefffffffffffff,
+                rna: 0x7ff0000000000000,
+            } raises(overflow, inexact),
+            sqrt(0x12345667ffffffff) => {
+                rne: 0x291209f295a7dbd3,
+                rtz: 0x291209f295a7dbd2,
+                rup: 0x291209f295a7dbd3,
+                rdn: 0x291209f295a7dbd2,
+                rna: 0x291209f295a7dbd3,
+            } raises(inexact),
+            fma(0x12345667ffffffff, 0x76543210aaaaaaaa, 0xc899aba53456fffe) => 0xc51c100aaaaaaaa0,
+            rem(0x76543210aaaaaaaa, 0x75432100bbbbbbbb) => 0xf5259048444367fc,
+            rem_gap = rem(0x76543210aaaaaaaa, 0x12345667ffffffff) => 0x920add62ac0785b0,
+            from_f128(0x3fff0000000000000800000000000000) => {
+                rne: 0x3ff0000000000000,
+                rtz: 0x3ff0000000000000,
+                rup: 0x3ff0000000000001,
+                rdn: 0x3ff0000000000000,
+                rna: 0x3ff0000000000001,
+            } raises(inexact),
+            from_f128_overflow = from_f128(0x43ff8000000000000000000000000000) => {
+                rne: 0x7ff0000000000000,
+                rtz: 0x7fefffffffffffff,
+                rup: 0x7ff0000000000000,
+                rdn: 0x7fefffffffffffff,
+                rna: 0x7ff0000000000000,
+            } raises(overflow, inexact),
+            from_f128_subnormal = from_f128(0x3bfc4ccccccccccccccccccccccccccd) => {
+                rne: 0x0000a66666666666,
+                rtz: 0x0000a66666666666,
+                rup: 0x0000a66666666667,
+                rdn: 0x0000a66666666666,
+                rna: 0x0000a66666666666,
+            } raises(underflow, inexact),
+            to_f128(0x000234567890abcd) => 0x3bfe1a2b3c4855e68000000000000000,
+            parse("0.1") => {
+                rne: 0x3fb999999999999a,
+                rtz: 0x3fb9999999999999,
+                rup: 0x3fb999999999999a,
+                rdn: 0x3fb9999999999999,
+                rna: 0x3fb999999999999a,
+            } raises(inexact),
+            // Just above 1 + 2^-53, the midpoint between 1 and the next f64.
+            parse_long = parse("1.00000000000000011102230246251565404236316680908203125000001") => {
+                rne: 0x3ff0000000000001,
+                rtz: 0x3ff0000000000000,
+                rup: 0x3ff0000000000001,
+                rdn: 0x3ff0000000000000,
+                rna: 0x3ff0000000000001,
+            } raises(inexact),
+            // pi.
+            parse_hex = parse("0x3.243f6a8885a308d313198a2e03707344ap0") => {
+                rne: 0x400921fb54442d18,
+                rtz: 0x400921fb54442d18,
+                rup: 0x400921fb54442d19,
+                rdn: 0x400921fb54442d18,
+                rna: 0x400921fb54442d18,
+            } raises(inexact),
+            format(0x3fb999999999999a) => { rne: "0.1" },
+            format_subnormal = format(0x0000000000000001) => { rne: "5e-324" },
+            eq(0x0000000000000000, 0x8000000000000000) => { rne: true },
+            eq_nan = eq(0x7ff8000000000000, 0x7ff8000000000000) => { rne: false },
+            eq_signaling(0x7ff8000000000000, 0x3ff0000000000000) => { rne: false } raises(invalid),
+            lt(0xc000000000000000, 0x3ff0000000000000) => { rne: true },
+            lt_nan = lt(0x3ff0000000000000, 0x7ff8000000000000) => { rne: false } raises(invalid),
+            lt_quiet(0x3ff0000000000000, 0x7ff8000000000000) => { rne: false },
+            le(0x8000000000000000, 0x0000000000000000) => { rne: true },
+            le_quiet(0x7ff0000000000001, 0x3ff0000000000000) => { rne: false } raises(invalid),
+            unordered(0x3ff0000000000000, 0x7ff8000000000000) => { rne: true },
+            unordered_snan = unordered(0x7ff0000000000001, 0x3ff0000000000000) => { rne: true } raises(invalid),
+            round_to_integral(0x4004000000000000) => {
+                rne: 0x4000000000000000,
+                rtz: 0x4000000000000000,
+                rup: 0x4008000000000000,
+                rdn: 0x4000000000000000,
+                rna: 0x4008000000000000,
+            },
+            round_to_integral_neg = round_to_integral(0xbfe0000000000000) => {
+                rne: 0x8000000000000000,
+                rtz: 0x8000000000000000,
+                rup: 0x8000000000000000,
+                rdn: 0xbff0000000000000,
+                rna: 0xbff0000000000000,
+            },
+            minimum(0x0000000000000000, 0x8000000000000000) => { rne: 0x8000000000000000 },
+            minimum_nan = minimum(0x3ff0000000000000, 0x7ff8000000000000) => { rne: 0x7ff8000000000000 },
+            maximum(0x8000000000000000, 0x0000000000000000) => { rne: 0x0000000000000000 },
+            maximum_nan = maximum(0x7ff8000000000000, 0x3ff0000000000000) => { rne: 0x7ff8000000000000 },
+            min_num(0xc000000000000000, 0x3ff0000000000000) => { rne: 0xc000000000000000 },
+            min_num_nan = min_num(0x3ff0000000000000, 0x7ff8000000000000) => { rne: 0x3ff0000000000000 },
+            min_num_nan_first = min_num(0x7ff8000000000000, 0x3ff0000000000000) => { rne: 0x3ff0000000000000 },
+            max_num(0xc000000000000000, 0x3ff0000000000000) => { rne: 0x3ff0000000000000 },
+            max_num_nan = max_num(0x7ff8000000000000, 0x3ff0000000000000) => { rne: 0x3ff0000000000000 },
+        }
+        f128: Binary128 {
+            add(
+                0x12345667ffffffffcccccccccccccccc,
+                0x76543210aaaaaaaacccccccccccccccc
+            ) => {
+                rne: 0x76543210aaaaaaaacccccccccccccccc,
+                rtz: 0x76543210aaaaaaaacccccccccccccccc,
+                rup: 0x76543210aaaaaaaacccccccccccccccd,
+                rdn: 0x76543210aaaaaaaacccccccccccccccc,
+                rna: 0x76543210aaaaaaaacccccccccccccccc,
+            } raises(inexact),
+            sub(
+                0x12345667ffffffffcccccccccccccccc,
+                0x76543210aaaaaaaacccccccccccccccc
+            ) => {
+                rne: 0xf6543210aaaaaaaacccccccccccccccc,
+                rtz: 0xf6543210aaaaaaaacccccccccccccccb,
+                rup: 0xf6543210aaaaaaaacccccccccccccccb,
+                rdn: 0xf6543210aaaaaaaacccccccccccccccc,
+                rna: 0xf6543210aaaaaaaacccccccccccccccc,
+            } raises(inexact),
+            mul(
+                0x12345667ffffffffcccccccccccccccc,
+                0x76543210aaaaaaaacccccccccccccccc
+            ) => {
+                rne: 0x4889995e9ac5555545c6444444443d6f,
+                rtz: 0x4889995e9ac5555545c6444444443d6e,
+                rup: 0x4889995e9ac5555545c6444444443d6f,
+                rdn: 0x4889995e9ac5555545c6444444443d6e,
+                rna: 0x4889995e9ac5555545c6444444443d6f,
+            } raises(inexact),
+            div(
+                0x76543210aaaaaaaacccccccccccccccc,
+                0x12345667ffffffffcccccccccccccccc
+            ) => {
+                rne: 0x7fff0000000000000000000000000000,
+                rtz: 0x7ffeffffffffffffffffffffffffffff,
+                rup: 0x7fff0000000000000000000000000000,
+                rdn: 0x7ffeffffffffffffffffffffffffffff,
+                rna: 0x7fff0000000000000000000000000000,
+            } raises(overflow, inexact),
+            sqrt(0x12345667ffffffffcccccccccccccccc) => {
+                rne: 0x2919a2b3e7fba418812c916df5a0bca5,
+                rtz: 0x2919a2b3e7fba418812c916df5a0bca4,
+                rup: 0x2919a2b3e7fba418812c916df5a0bca5,
+                rdn: 0x2919a2b3e7fba418812c916df5a0bca4,
+                rna: 0x2919a2b3e7fba418812c916df5a0bca5,
+            } raises(inexact),
+            fma(
+                0x12345667ffffffffcccccccccccccccc,
+                0x76543210aaaaaaaacccccccccccccccc,
+                0xc889995e9ac5555545c6444444443d6f
+            ) => 0xc8178bbf92c5f92c28f5c28f5c28f5c0,
+            rem(
+                0x76543210aaaaaaaacccccccccccccccc,
+                0x76432100bbbbbbbbdddddddddddddddd
+            ) => 0x764136cd5555453666666666666757b8,
+            rem_gap = rem(
+                0x76543210aaaaaaaacccccccccccccccc,
+                0x12345667ffffffffcccccccccccccccc
+            ) => 0x1232ed1330e468bb12c76c2219bad1a0,
+            parse("0.1") => {
+                rne: 0x3ffb999999999999999999999999999a,
+                rtz: 0x3ffb9999999999999999999999999999,
+                rup: 0x3ffb999999999999999999999999999a,
+                rdn: 0x3ffb9999999999999999999999999999,
+                rna: 0x3ffb999999999999999999999999999a,
+            } raises(inexact),
+            // Just above 1 + 2^-113, the midpoint between 1 and the next f128.
+            parse_long = parse("1.000000000000000000000000000000000096296497219361792652798897129246365926905082410769409761996939778327941894531251") => {
+                rne: 0x3fff0000000000000000000000000001,
+                rtz: 0x3fff0000000000000000000000000000,
+                rup: 0x3fff0000000000000000000000000001,
+                rdn: 0x3fff0000000000000000000000000000,
+                rna: 0x3fff0000000000000000000000000001,
+            } raises(inexact),
+            // pi.
+            parse_hex = parse("0x3.243f6a8885a308d313198a2e03707344ap0") => {
+                rne: 0x4000921fb54442d18469898cc51701b8,
+                rtz: 0x4000921fb54442d18469898cc51701b8,
+                rup: 0x4000921fb54442d18469898cc51701b9,
+                rdn: 0x4000921fb54442d18469898cc51701b8,
+                rna: 0x4000921fb54442d18469898cc51701b8,
+            } raises(inexact),
+            format(0x3ffb999999999999999999999999999a) => { rne: "0.1" },
+            format_subnormal = format(0x00000000000000000000000000000001) => { rne: "6e-4966" },
+            eq(0x00000000000000000000000000000000, 0x80000000000000000000000000000000) => { rne: true },
+            eq_nan = eq(0x7fff8000000000000000000000000000, 0x7fff8000000000000000000000000000) => { rne: false },
+            eq_signaling(0x7fff8000000000000000000000000000, 0x3fff0000000000000000000000000000) => { rne: false } raises(invalid),
+            lt(0xc0000000000000000000000000000000, 0x3fff0000000000000000000000000000) => { rne: true },
+            lt_nan = lt(0x3fff0000000000000000000000000000, 0x7fff8000000000000000000000000000) => { rne: false } raises(invalid),
+            lt_quiet(0x3fff0000000000000000000000000000, 0x7fff8000000000000000000000000000) => { rne: false },
+            le(0x80000000000000000000000000000000, 0x00000000000000000000000000000000) => { rne: true },
+            le_quiet(0x7fff0000000000000000000000000001, 0x3fff0000000000000000000000000000) => { rne: false } raises(invalid),
+            unordered(0x3fff0000000000000000000000000000, 0x7fff8000000000000000000000000000) => { rne: true },
+            unordered_snan = unordered(0x7fff0000000000000000000000000001, 0x3fff0000000000000000000000000000) => { rne: true } raises(invalid),
+            round_to_integral(0x40004000000000000000000000000000) => {
+                rne: 0x40000000000000000000000000000000,
+                rtz: 0x40000000000000000000000000000000,
+                rup: 0x40008000000000000000000000000000,
+                rdn: 0x40000000000000000000000000000000,
+                rna: 0x40008000000000000000000000000000,
+            },
+            round_to_integral_neg = round_to_integral(0xbffe0000000000000000000000000000) => {
+                rne: 0x80000000000000000000000000000000,
+                rtz: 0x80000000000000000000000000000000,
+                rup: 0x80000000000000000000000000000000,
+                rdn: 0xbfff0000000000000000000000000000,
+                rna: 0xbfff0000000000000000000000000000,
+            },
+            minimum(0x00000000000000000000000000000000, 0x80000000000000000000000000000000) => { rne: 0x80000000000000000000000000000000 },
+            minimum_nan = minimum(0x3fff0000000000000000000000000000, 0x7fff8000000000000000000000000000) => { rne: 0x7fff8000000000000000000000000000 },
+            maximum(0x80000000000000000000000000000000, 0x00000000000000000000000000000000) => { rne: 0x00000000000000000000000000000000 },
+            maximum_nan = maximum(0x7fff8000000000000000000000000000, 0x3fff0000000000000000000000000000) => { rne: 0x7fff8000000000000000000000000000 },
+            min_num(0xc0000000000000000000000000000000, 0x3fff0000000000000000000000000000) => { rne: 0xc0000000000000000000000000000000 },
+            min_num_nan = min_num(0x3fff0000000000000000000000000000, 0x7fff8000000000000000000000000000) => { rne: 0x3fff0000000000000000000000000000 },
+            min_num_nan_first = min_num(0x7fff8000000000000000000000000000, 0x3fff0000000000000000000000000000) => { rne: 0x3fff0000000000000000000000000000 },
+            max_num(0xc0000000000000000000000000000000, 0x3fff0000000000000000000000000000) => { rne: 0x3fff0000000000000000000000000000 },
+            max_num_nan = max_num(0x7fff8000000000000000000000000000, 0x3fff0000000000000000000000000000) => { rne: 0x3fff0000000000000000000000000000 },
+        }
+        f80: X87DoubleExtended {
+            add(0x1234aaaaaaaaaaaaaaab, 0x7654cccccccccccccccd) => {
+                rne: 0x7654cccccccccccccccd,
+                rtz: 0x7654cccccccccccccccd,
+                rup: 0x7654ccccccccccccccce,
+                rdn: 0x7654cccccccccccccccd,
+                rna: 0x7654cccccccccccccccd,
+            } raises(inexact),
+            sub(0x1234aaaaaaaaaaaaaaab, 0x7654cccccccccccccccd) => {
+                rne: 0xf654cccccccccccccccd,
+                rtz: 0xf654cccccccccccccccc,
+                rup: 0xf654cccccccccccccccc,
+                rdn: 0xf654cccccccccccccccd,
+                rna: 0xf654cccccccccccccccd,
+            } raises(inexact),
+            mul(0x1234aaaaaaaaaaaaaaab, 0x7654cccccccccccccccd) => {
+                rne: 0x488a8888888888888889,
+                rtz: 0x488a8888888888888888,
+                rup: 0x488a8888888888888889,
+                rdn: 0x488a8888888888888888,
+                rna: 0x488a8888888888888889,
+            } raises(inexact),
+            div(0x7654cccccccccccccccd, 0x1234aaaaaaaaaaaaaaab) => {
+                rne: 0x7fff8000000000000000,
+                rtz: 0x7ffeffffffffffffffff,
+                rup: 0x7fff8000000000000000,
+                rdn: 0x7ffeffffffffffffffff,
+                rna: 0x7fff8000000000000000,
+            } raises(overflow, inexact),
+            sqrt(0x1234aaaaaaaaaaaaaaab) => {
+                rne: 0x2919d105eb806161efe0,
+                rtz: 0x2919d105eb806161efe0,
+                rup: 0x2919d105eb806161efe1,
+                rdn: 0x2919d105eb806161efe0,
+                rna: 0x2919d105eb806161efe0,
+            } raises(inexact),
+            fma(
+                0x1234aaaaaaaaaaaaaaab,
+                0x7654cccccccccccccccd,
+                0xc88a8888888888888889
+            ) => 0xc8478888888888888888,
+            rem(0x7654cccccccccccccccd, 0x7643bbbbbbbbbbbbbbbb) => 0xf6428888888888848888,
+            rem_gap = rem(0x7654cccccccccccccccd, 0x1234aaaaaaaaaaaaaaab) => 0x92328888888888bbbbbc,
+            // The smallest denormal's significand with the integer bit set,
+            // times 1.0.
+            mul_pseudo_denormal = mul(
+                0x00008000000000000001,
+                0x3fff8000000000000000
+            ) => 0x00018000000000000001,
+            eq(0x00000000000000000000, 0x80000000000000000000) => { rne: true },
+            eq_nan = eq(0x7fffc000000000000000, 0x7fffc000000000000000) => { rne: false },
+            eq_signaling(0x7fffc000000000000000, 0x3fff8000000000000000) => { rne: false } raises(invalid),
+            lt(0xc0008000000000000000, 0x3fff8000000000000000) => { rne: true },
+            lt_nan = lt(0x3fff8000000000000000, 0x7fffc000000000000000) => { rne: false } raises(invalid),
+            lt_quiet(0x3fff8000000000000000, 0x7fffc000000000000000) => { rne: false },
+            le(0x80000000000000000000, 0x00000000000000000000) => { rne: true },
+            le_quiet(0x7fff8000000000000001, 0x3fff8000000000000000) => { rne: false } raises(invalid),
+            unordered(0x3fff8000000000000000, 0x7fffc000000000000000) => { rne: true },
+            unordered_snan = unordered(0x7fff8000000000000001, 0x3fff8000000000000000) => { rne: true } raises(invalid),
+            round_to_integral(0x4000a000000000000000) => {
+                rne: 0x40008000000000000000,
+                rtz: 0x40008000000000000000,
+                rup: 0x4000c000000000000000,
+                rdn: 0x40008000000000000000,
+                rna: 0x4000c000000000000000,
+            },
+            round_to_integral_neg = round_to_integral(0xbffe8000000000000000) => {
+                rne: 0x80000000000000000000,
+                rtz: 0x80000000000000000000,
+                rup: 0x80000000000000000000,
+                rdn: 0xbfff8000000000000000,
+                rna: 0xbfff8000000000000000,
+            },
+            minimum(0x00000000000000000000, 0x80000000000000000000) => { rne: 0x80000000000000000000 },
+            minimum_nan = minimum(0x3fff8000000000000000, 0x7fffc000000000000000) => { rne: 0x7fffc000000000000000 },
+            maximum(0x80000000000000000000, 0x00000000000000000000) => { rne: 0x00000000000000000000 },
+            maximum_nan = maximum(0x7fffc000000000000000, 0x3fff8000000000000000) => { rne: 0x7fffc000000000000000 },
+            min_num(0xc0008000000000000000, 0x3fff8000000000000000) => { rne: 0xc0008000000000000000 },
+            min_num_nan = min_num(0x3fff8000000000000000, 0x7fffc000000000000000) => { rne: 0x3fff8000000000000000 },
+            min_num_nan_first = min_num(0x7fffc000000000000000, 0x3fff8000000000000000) => { rne: 0x3fff8000000000000000 },
+            max_num(0xc0008000000000000000, 0x3fff8000000000000000) => { rne: 0x3fff8000000000000000 },
+            max_num_nan = max_num(0x7fffc000000000000000, 0x3fff8000000000000000) => { rne: 0x3fff8000000000000000 },
+        }
+        bf16: BFloat16 {
+            add(0x1234, 0x7654) => {
+                rne: 0x7654,
+                rtz: 0x7654,
+                rup: 0x7655,
+                rdn: 0x7654,
+                rna: 0x7654,
+            } raises(inexact),
+            mul(0x1234, 0x7654) => {
+                rne: 0x4915,
+                rtz: 0x4915,
+                rup: 0x4916,
+                rdn: 0x4915,
+                rna: 0x4915,
+            } raises(inexact),
+            div(0x7654, 0x1234) => {
+                rne: 0x7f80,
+                rtz: 0x7f7f,
+                rup: 0x7f80,
+                rdn: 0x7f7f,
+                rna: 0x7f80,
+            } raises(overflow, inexact),
+            fma(0x1234, 0x7654, 0xc915) => 0x4380,
+            // 1 + 2^-8, halfway between 1 and the next bfloat16.
+            from_f32(0x3f808000) => {
+                rne: 0x3f80,
+                rtz: 0x3f80,
+                rup: 0x3f81,
+                rdn: 0x3f80,
+                rna: 0x3f81,
+            } raises(inexact),
+            to_f32(0x1234) => 0x12340000,
+            parse("0.1") => {
+                rne: 0x3dcd,
+                rtz: 0x3dcc,
+                rup: 0x3dcd,
+                rdn: 0x3dcc,
+                rna: 0x3dcd,
+            } raises(inexact),
+            // Just above 1 + 2^-8, the midpoint between 1 and the next bf16.
+            parse_long = parse("1.00390625000000000000000000000000000000000000000000000000001") => {
+                rne: 0x3f81,
+                rtz: 0x3f80,
+                rup: 0x3f81,
+                rdn: 0x3f80,
+                rna: 0x3f81,
+            } raises(inexact),
+            // pi.
+            parse_hex = parse("0x3.243f6a8885a308d313198a2e03707344ap0") => {
+                rne: 0x4049,
+                rtz: 0x4049,
+                rup: 0x404a,
+                rdn: 0x4049,
+                rna: 0x4049,
+            } raises(inexact),
+            format(0x3dcd) => { rne: "0.1" },
+            format_subnormal = format(0x0001) => { rne: "9e-41" },
+            eq(0x0000, 0x8000) => { rne: true },
+            eq_nan = eq(0x7fc0, 0x7fc0) => { rne: false },
+            eq_signaling(0x7fc0, 0x3f80) => { rne: false } raises(invalid),
+            lt(0xc000, 0x3f80) => { rne: true },
+            lt_nan = lt(0x3f80, 0x7fc0) => { rne: false } raises(invalid),
+            lt_quiet(0x3f80, 0x7fc0) => { rne: false },
+            le(0x8000, 0x0000) => { rne: true },
+            le_quiet(0x7f81, 0x3f80) => { rne: false } raises(invalid),
+            unordered(0x3f80, 0x7fc0) => { rne: true },
+            unordered_snan = unordered(0x7f81, 0x3f80) => { rne: true } raises(invalid),
+            round_to_integral(0x4020) => {
+                rne: 0x4000,
+                rtz: 0x4000,
+                rup: 0x4040,
+                rdn: 0x4000,
+                rna: 0x4040,
+            },
+            round_to_integral_neg = round_to_integral(0xbf00) => {
+                rne: 0x8000,
+                rtz: 0x8000,
+                rup: 0x8000,
+                rdn: 0xbf80,
+                rna: 0xbf80,
+            },
+            minimum(0x0000, 0x8000) => { rne: 0x8000 },
+            minimum_nan = minimum(0x3f80, 0x7fc0) => { rne: 0x7fc0 },
+            maximum(0x8000, 0x0000) => { rne: 0x0000 },
+            maximum_nan = maximum(0x7fc0, 0x3f80) => { rne: 0x7fc0 },
+            min_num(0xc000, 0x3f80) => { rne: 0xc000 },
+            min_num_nan = min_num(0x3f80, 0x7fc0) => { rne: 0x3f80 },
+            min_num_nan_first = min_num(0x7fc0, 0x3f80) => { rne: 0x3f80 },
+            max_num(0xc000, 0x3f80) => { rne: 0x3f80 },
+            max_num_nan = max_num(0x7fc0, 0x3f80) => { rne: 0x3f80 },
+        }
     }
-
-    #[bench]
-    fn div_rustc_apfloat(b: &mut Bencher) {
-        use rustc_apfloat::Float;
-        b.iter(|| {
-            let a = test::black_box(0x76543210aaaaaaaau64);
-            let b = test::black_box(0x12345667ffffffffu64);
-            let a = Double::from_bits(a as u128);
-            let b = Double::from_bits(b as u128);
-            let d = a.div_r(b, Round::NearestTiesToEven);
-            assert_eq!(d.value.to_bits(), 9218868437227405312u128);
-            d
-        });
+    backends {
+        simple_soft_float: SimpleSoftFloat,
+        softfloat_sys: SoftfloatSys,
+        softfloat_pure: SoftfloatPure,
+        const_soft_float: ConstSoftFloat,
+        half: Half,
+        rug: Rug,
+        rustc_apfloat: RustcApfloat,
+        compiler_builtins: CompilerBuiltins,
+        native: Native,
     }
 }
 
-mod f128 {
-    use super::*;
-
-    #[bench]
-    fn add_simple_soft_float(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667ffffffffccccccccccccccccu128);
-            let b = test::black_box(0x76543210aaaaaaaaccccccccccccccccu128);
-            let a = F128::from_bits(a);
-            let b = F128::from_bits(b);
-            let d = a.add(&b, None, None);
-            assert_eq!(*d.bits(), 157286071879686556347165517936193227980u128);
-            d
-        });
+bench_sets! {
+    formats {
+        f16: Binary16,
+        f32: Binary32,
+        f64: Binary64,
+        f128: Binary128,
+        bf16: BFloat16,
     }
-
-    #[bench]
-    fn add_softfloat_sys(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667ffffffffccccccccccccccccu128);
-            let b = test::black_box(0x76543210aaaaaaaaccccccccccccccccu128);
-            let a = float128_t {
-                v: [a as u64, (a >> 64) as u64],
-            };
-            let b = float128_t {
-                v: [b as u64, (b >> 64) as u64],
-            };
-            let d = unsafe { f128_add(a, b) };
-            let mut x = 0u128;
-            x |= d.v[0] as u128;
-            x |= (d.v[1] as u128) << 64;
-            assert_eq!(x, 157286071879686556347165517936193227980u128);
-            x
-        });
+    operations {
+        add,
+        mul,
+        div,
+        sqrt,
     }
-
-    #[bench]
-    fn add_rustc_apfloat(b: &mut Bencher) {
-        use rustc_apfloat::Float;
-        b.iter(|| {
-            let a = test::black_box(0x12345667ffffffffccccccccccccccccu128);
-            let b = test::black_box(0x76543210aaaaaaaaccccccccccccccccu128);
-            let a = Quad::from_bits(a);
-            let b = Quad::from_bits(b);
-            let d = a.add_r(b, Round::NearestTiesToEven);
-            assert_eq!(
-                d.value.to_bits(),
-                157286071879686556347165517936193227980u128
-            );
-            d
-        });
+    sets {
+        corpus: edge_cases {
+            zero,
+            subnormal,
+            min_normal,
+            max_finite,
+            infinity,
+            quiet_nan,
+            signaling_nan,
+            boundary,
+        },
+        stream: random {
+            uniform_bits,
+            uniform_finite,
+            log_uniform,
+            near_one,
+            subnormal_heavy,
+        },
     }
-
-    #[bench]
-    fn mul_simple_soft_float(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667ffffffffccccccccccccccccu128);
-            let b = test::black_box(0x76543210aaaaaaaaccccccccccccccccu128);
-            let a = F128::from_bits(a);
-            let b = F128::from_bits(b);
-            let d = a.mul(&b, None, None);
-            assert_eq!(*d.bits(), 96418871070149102153708677870054030703u128);
-            d
-        });
+    backends {
+        simple_soft_float: SimpleSoftFloat,
+        softfloat_sys: SoftfloatSys,
+        softfloat_pure: SoftfloatPure,
+        const_soft_float: ConstSoftFloat,
+        half: Half,
+        rug: Rug,
+        rustc_apfloat: RustcApfloat,
+        compiler_builtins: CompilerBuiltins,
+        native: Native,
     }
+}
 
-    #[bench]
-    fn mul_softfloat_sys(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x12345667ffffffffccccccccccccccccu128);
-            let b = test::black_box(0x76543210aaaaaaaaccccccccccccccccu128);
-            let a = float128_t {
-                v: [a as u64, (a >> 64) as u64],
-            };
-            let b = float128_t {
-                v: [b as u64, (b >> 64) as u64],
-            };
-            let d = unsafe { f128_mul(a, b) };
-            let mut x = 0u128;
-            x |= d.v[0] as u128;
-            x |= (d.v[1] as u128) << 64;
-            assert_eq!(x, 96418871070149102153708677870054030703u128);
-            x
-        });
+bench_platforms! {
+    platforms {
+        riscv: RISC_V,
+        x86: X86,
+        arm: ARM,
     }
-
-    #[bench]
-    fn mul_rustc_apfloat(b: &mut Bencher) {
-        use rustc_apfloat::Float;
-        b.iter(|| {
-            let a = test::black_box(0x12345667ffffffffccccccccccccccccu128);
-            let b = test::black_box(0x76543210aaaaaaaaccccccccccccccccu128);
-            let a = Quad::from_bits(a);
-            let b = Quad::from_bits(b);
-            let d = a.mul_r(b, Round::NearestTiesToEven);
-            assert_eq!(
-                d.value.to_bits(),
-                96418871070149102153708677870054030703u128
-            );
-            d
-        });
+    formats {
+        f16: Binary16,
+        f32: Binary32,
+        f64: Binary64,
+        f128: Binary128,
+        bf16: BFloat16,
     }
-
-    #[bench]
-    fn div_simple_soft_float(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x76543210aaaaaaaaccccccccccccccccu128);
-            let b = test::black_box(0x12345667ffffffffccccccccccccccccu128);
-            let a = F128::from_bits(a);
-            let b = F128::from_bits(b);
-            let d = a.div(&b, None, None);
-            assert_eq!(*d.bits(), 170135991163610696904058773219554885632u128);
-            d
-        });
+    backends {
+        simple_soft_float: SimpleSoftFloat,
+        softfloat_sys: SoftfloatSys,
+        softfloat_pure: SoftfloatPure,
+        const_soft_float: ConstSoftFloat,
+        half: Half,
+        rug: Rug,
+        rustc_apfloat: RustcApfloat,
     }
+}
 
-    #[bench]
-    fn div_softfloat_sys(b: &mut Bencher) {
-        b.iter(|| {
-            let a = test::black_box(0x76543210aaaaaaaaccccccccccccccccu128);
-            let b = test::black_box(0x12345667ffffffffccccccccccccccccu128);
-            let a = float128_t {
-                v: [a as u64, (a >> 64) as u64],
-            };
-            let b = float128_t {
-                v: [b as u64, (b >> 64) as u64],
-            };
-            let d = unsafe { f128_div(a, b) };
-            let mut x = 0u128;
-            x |= d.v[0] as u128;
-            x |= (d.v[1] as u128) << 64;
-            assert_eq!(x, 170135991163610696904058773219554885632u128);
-            x
-        });
+// `from` converts the integer type's largest value, negated for signed types,
+// which overflows the narrow formats. `u128::MAX` only overflows `f32` when
+// rounding up, and the table has one set of flags per row, so that row takes
+// half of it. `to` converts the largest value halfway between two integers
+// that the format holds and the integer type's range allows.
+bench_ints! {
+    formats {
+        f16: Binary16 {
+            i32 {
+                from(i32::MIN + 1) => {
+                    rne: 0xfc00,
+                    rtz: 0xfbff,
+                    rup: 0xfbff,
+                    rdn: 0xfc00,
+                    rna: 0xfc00,
+                } raises(overflow, inexact),
+                to(0xe001) => {
+                    rne: -(1 << 9),
+                    rtz: -(1 << 9),
+                    rup: -(1 << 9),
+                    rdn: -(1 << 9) - 1,
+                    rna: -(1 << 9) - 1,
+                } raises(inexact),
+            }
+            u32 {
+                from(u32::MAX) => {
+                    rne: 0x7c00,
+                    rtz: 0x7bff,
+                    rup: 0x7c00,
+                    rdn: 0x7bff,
+                    rna: 0x7c00,
+                } raises(overflow, inexact),
+                to(0x6001) => {
+                    rne: 1 << 9,
+                    rtz: 1 << 9,
+                    rup: (1 << 9) + 1,
+                    rdn: 1 << 9,
+                    rna: (1 << 9) + 1,
+                } raises(inexact),
+            }
+            i64 {
+                from(i64::MIN + 1) => {
+                    rne: 0xfc00,
+                    rtz: 0xfbff,
+                    rup: 0xfbff,
+                    rdn: 0xfc00,
+                    rna: 0xfc00,
+                } raises(overflow, inexact),
+                to(0xe001) => {
+                    rne: -(1 << 9),
+                    rtz: -(1 << 9),
+                    rup: -(1 << 9),
+                    rdn: -(1 << 9) - 1,
+                    rna: -(1 << 9) - 1,
+                } raises(inexact),
+            }
+            u64 {
+                from(u64::MAX) => {
+                    rne: 0x7c00,
+                    rtz: 0x7bff,
+                    rup: 0x7c00,
+                    rdn: 0x7bff,
+                    rna: 0x7c00,
+                } raises(overflow, inexact),
+                to(0x6001) => {
+                    rne: 1 << 9,
+                    rtz: 1 << 9,
+                    rup: (1 << 9) + 1,
+                    rdn: 1 << 9,
+                    rna: (1 << 9) + 1,
+                } raises(inexact),
+            }
+            i128 {
+                from(i128::MIN + 1) => {
+                    rne: 0xfc00,
+                    rtz: 0xfbff,
+                    rup: 0xfbff,
+                    rdn: 0xfc00,
+                    rna: 0xfc00,
+                } raises(overflow, inexact),
+                to(0xe001) => {
+                    rne: -(1 << 9),
+                    rtz: -(1 << 9),
+                    rup: -(1 << 9),
+                    rdn: -(1 << 9) - 1,
+                    rna: -(1 << 9) - 1,
+                } raises(inexact),
+            }
+            u128 {
+                from(u128::MAX) => {
+                    rne: 0x7c00,
+                    rtz: 0x7bff,
+                    rup: 0x7c00,
+                    rdn: 0x7bff,
+                    rna: 0x7c00,
+                } raises(overflow, inexact),
+                to(0x6001) => {
+                    rne: 1 << 9,
+                    rtz: 1 << 9,
+                    rup: (1 << 9) + 1,
+                    rdn: 1 << 9,
+                    rna: (1 << 9) + 1,
+                } raises(inexact),
+            }
+        }
+        f32: Binary32 {
+            i32 {
+                from(i32::MIN + 1) => {
+                    rne: 0xcf000000,
+                    rtz: 0xceffffff,
+                    rup: 0xceffffff,
+                    rdn: 0xcf000000,
+                    rna: 0xcf000000,
+                } raises(inexact),
+                to(0xca800001) => {
+                    rne: -(1 << 22),
+                    rtz: -(1 << 22),
+                    rup: -(1 << 22),
+                    rdn: -(1 << 22) - 1,
+                    rna: -(1 << 22) - 1,
+                } raises(inexact),
+            }
+            u32 {
+                from(u32::MAX) => {
+                    rne: 0x4f800000,
+                    rtz: 0x4f7fffff,
+                    rup: 0x4f800000,
+                    rdn: 0x4f7fffff,
+                    rna: 0x4f800000,
+                } raises(inexact),
+                to(0x4a800001) => {
+                    rne: 1 << 22,
+                    rtz: 1 << 22,
+                    rup: (1 << 22) + 1,
+                    rdn: 1 << 22,
+                    rna: (1 << 22) + 1,
+                } raises(inexact),
+            }
+            i64 {
+                from(i64::MIN + 1) => {
+                    rne: 0xdf000000,
+                    rtz: 0xdeffffff,
+                    rup: 0xdeffffff,
+                    rdn: 0xdf000000,
+                    rna: 0xdf000000,
+                } raises(inexact),
+                to(0xca800001) => {
+                    rne: -(1 << 22),
+                    rtz: -(1 << 22),
+                    rup: -(1 << 22),
+                    rdn: -(1 << 22) - 1,
+                    rna: -(1 << 22) - 1,
+                } raises(inexact),
+            }
+            u64 {
+                from(u64::MAX) => {
+                    rne: 0x5f800000,
+                    rtz: 0x5f7fffff,
+                    rup: 0x5f800000,
+                    rdn: 0x5f7fffff,
+                    rna: 0x5f800000,
+                } raises(inexact),
+                to(0x4a800001) => {
+                    rne: 1 << 22,
+                    rtz: 1 << 22,
+                    rup: (1 << 22) + 1,
+                    rdn: 1 << 22,
+                    rna: (1 << 22) + 1,
+                } raises(inexact),
+            }
+            i128 {
+                from(i128::MIN + 1) => {
+                    rne: 0xff000000,
+                    rtz: 0xfeffffff,
+                    rup: 0xfeffffff,
+                    rdn: 0xff000000,
+                    rna: 0xff000000,
+                } raises(inexact),
+                to(0xca800001) => {
+                    rne: -(1 << 22),
+                    rtz: -(1 << 22),
+                    rup: -(1 << 22),
+                    rdn: -(1 << 22) - 1,
+                    rna: -(1 << 22) - 1,
+                } raises(inexact),
+            }
+            u128 {
+                from(u128::MAX >> 1) => {
+                    rne: 0x7f000000,
+                    rtz: 0x7effffff,
+                    rup: 0x7f000000,
+                    rdn: 0x7effffff,
+                    rna: 0x7f000000,
+                } raises(inexact),
+                to(0x4a800001) => {
+                    rne: 1 << 22,
+                    rtz: 1 << 22,
+                    rup: (1 << 22) + 1,
+                    rdn: 1 << 22,
+                    rna: (1 << 22) + 1,
+                } raises(inexact),
+            }
+        }
+        f64: Binary64 {
+            i32 {
+                from(i32::MIN + 1) => 0xc1dfffffffc00000,
+                to(0xc1d0000000200000) => {
+                    rne: -(1 << 30),
+                    rtz: -(1 << 30),
+                    rup: -(1 << 30),
+                    rdn: -(1 << 30) - 1,
+                    rna: -(1 << 30) - 1,
+                } raises(inexact),
+            }
+            u32 {
+                from(u32::MAX) => 0x41efffffffe00000,
+                to(0x41e0000000100000) => {
+                    rne: 1 << 31,
+                    rtz: 1 << 31,
+                    rup: (1 << 31) + 1,
+                    rdn: 1 << 31,
+                    rna: (1 << 31) + 1,
+                } raises(inexact),
+            }
+            i64 {
+                from(i64::MIN + 1) => {
+                    rne: 0xc3e0000000000000,
+                    rtz: 0xc3dfffffffffffff,
+                    rup: 0xc3dfffffffffffff,
+                    rdn: 0xc3e0000000000000,
+                    rna: 0xc3e0000000000000,
+                } raises(inexact),
+                to(0xc320000000000001) => {
+                    rne: -(1 << 51),
+                    rtz: -(1 << 51),
+                    rup: -(1 << 51),
+                    rdn: -(1 << 51) - 1,
+                    rna: -(1 << 51) - 1,
+                } raises(inexact),
+            }
+            u64 {
+                from(u64::MAX) => {
+                    rne: 0x43f0000000000000,
+                    rtz: 0x43efffffffffffff,
+                    rup: 0x43f0000000000000,
+                    rdn: 0x43efffffffffffff,
+                    rna: 0x43f0000000000000,
+                } raises(inexact),
+                to(0x4320000000000001) => {
+                    rne: 1 << 51,
+                    rtz: 1 << 51,
+                    rup: (1 << 51) + 1,
+                    rdn: 1 << 51,
+                    rna: (1 << 51) + 1,
+                } raises(inexact),
+            }
+            i128 {
+                from(i128::MIN + 1) => {
+                    rne: 0xc7e0000000000000,
+                    rtz: 0xc7dfffffffffffff,
+                    rup: 0xc7dfffffffffffff,
+                    rdn: 0xc7e0000000000000,
+                    rna: 0xc7e0000000000000,
+                } raises(inexact),
+                to(0xc320000000000001) => {
+                    rne: -(1 << 51),
+                    rtz: -(1 << 51),
+                    rup: -(1 << 51),
+                    rdn: -(1 << 51) - 1,
+                    rna: -(1 << 51) - 1,
+                } raises(inexact),
+            }
+            u128 {
+                from(u128::MAX) => {
+                    rne: 0x47f0000000000000,
+                    rtz: 0x47efffffffffffff,
+                    rup: 0x47f0000000000000,
+                    rdn: 0x47efffffffffffff,
+                    rna: 0x47f0000000000000,
+                } raises(inexact),
+                to(0x4320000000000001) => {
+                    rne: 1 << 51,
+                    rtz: 1 << 51,
+                    rup: (1 << 51) + 1,
+                    rdn: 1 << 51,
+                    rna: (1 << 51) + 1,
+                } raises(inexact),
+            }
+        }
+        f128: Binary128 {
+            i32 {
+                from(i32::MIN + 1) => 0xc01dfffffffc00000000000000000000,
+                to(0xc01d0000000200000000000000000000) => {
+                    rne: -(1 << 30),
+                    rtz: -(1 << 30),
+                    rup: -(1 << 30),
+                    rdn: -(1 << 30) - 1,
+                    rna: -(1 << 30) - 1,
+                } raises(inexact),
+            }
+            u32 {
+                from(u32::MAX) => 0x401efffffffe00000000000000000000,
+                to(0x401e0000000100000000000000000000) => {
+                    rne: 1 << 31,
+                    rtz: 1 << 31,
+                    rup: (1 << 31) + 1,
+                    rdn: 1 << 31,
+                    rna: (1 << 31) + 1,
+                } raises(inexact),
+            }
+            i64 {
+                from(i64::MIN + 1) => 0xc03dfffffffffffffffc000000000000,
+                to(0xc03d0000000000000002000000000000) => {
+                    rne: -(1 << 62),
+                    rtz: -(1 << 62),
+                    rup: -(1 << 62),
+                    rdn: -(1 << 62) - 1,
+                    rna: -(1 << 62) - 1,
+                } raises(inexact),
+            }
+            u64 {
+                from(u64::MAX) => 0x403efffffffffffffffe000000000000,
+                to(0x403e0000000000000001000000000000) => {
+                    rne: 1 << 63,
+                    rtz: 1 << 63,
+                    rup: (1 << 63) + 1,
+                    rdn: 1 << 63,
+                    rna: (1 << 63) + 1,
+                } raises(inexact),
+            }
+            i128 {
+                from(i128::MIN + 1) => {
+                    rne: 0xc07e0000000000000000000000000000,
+                    rtz: 0xc07dffffffffffffffffffffffffffff,
+                    rup: 0xc07dffffffffffffffffffffffffffff,
+                    rdn: 0xc07e0000000000000000000000000000,
+                    rna: 0xc07e0000000000000000000000000000,
+                } raises(inexact),
+                to(0xc06e0000000000000000000000000001) => {
+                    rne: -(1 << 111),
+                    rtz: -(1 << 111),
+                    rup: -(1 << 111),
+                    rdn: -(1 << 111) - 1,
+                    rna: -(1 << 111) - 1,
+                } raises(inexact),
+            }
+            u128 {
+                from(u128::MAX) => {
+                    rne: 0x407f0000000000000000000000000000,
+                    rtz: 0x407effffffffffffffffffffffffffff,
+                    rup: 0x407f0000000000000000000000000000,
+                    rdn: 0x407effffffffffffffffffffffffffff,
+                    rna: 0x407f0000000000000000000000000000,
+                } raises(inexact),
+                to(0x406e0000000000000000000000000001) => {
+                    rne: 1 << 111,
+                    rtz: 1 << 111,
+                    rup: (1 << 111) + 1,
+                    rdn: 1 << 111,
+                    rna: (1 << 111) + 1,
+                } raises(inexact),
+            }
+        }
     }
-
-    #[bench]
-    fn div_rustc_apfloat(b: &mut Bencher) {
-        use rustc_apfloat::Float;
-        b.iter(|| {
-            let a = test::black_box(0x76543210aaaaaaaaccccccccccccccccu128);
-            let b = test::black_box(0x12345667ffffffffccccccccccccccccu128);
-            let a = Quad::from_bits(a as u128);
-            let b = Quad::from_bits(b as u128);
-            let d = a.div_r(b, Round::NearestTiesToEven);
-            assert_eq!(
-                d.value.to_bits(),
-                170135991163610696904058773219554885632u128
-            );
-            d
-        });
+    backends {
+        simple_soft_float: SimpleSoftFloat,
+        softfloat_sys: SoftfloatSys,
+        softfloat_pure: SoftfloatPure,
+        const_soft_float: ConstSoftFloat,
+        half: Half,
+        rug: Rug,
+        rustc_apfloat: RustcApfloat,
+        compiler_builtins: CompilerBuiltins,
+        native: Native,
     }
 }
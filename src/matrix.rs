@@ -0,0 +1,615 @@
+//! Generation of the benchmark matrix.
+//!
+//! [`bench_matrix!`] expands a table of formats (each with its operations and
+//! operands) and backends into one `#[bench]` per combination, named
+//! `<format>::<operation>::<rounding mode>::<backend>`. Expected results are
+//! given either once, when every rounding mode agrees, or per mode as
+//! `{ rne: .., rtz: .., rup: .., rdn: .., rna: .. }`, and may be followed by
+//! the exception flags the operation raises in every mode, e.g.
+//! `raises(overflow, inexact)`; without `raises` no flags are expected.
+//!
+//! Each row calls the body of `crate::bench` it is named after. A row
+//! exercising a particular case of another body names it after `=`, e.g.
+//! `eq_nan = eq(..)` benches `crate::bench::eq` under `<format>::eq_nan`.
+//!
+//! Whether a combination exists is decided by the `support!` macro defined
+//! next to the table: it forwards to a callback with `supported`,
+//! `unsupported` or `incorrect`, and unsupported combinations are emitted as
+//! benches ignored with the reason `unsupported` so they stay visible in the
+//! output. Those a library implements but gets wrong are ignored with the
+//! reason `incorrect` instead. Calls go
+//! through `op_class!`, which first passes the operation's class, e.g. `text`
+//! for parsing and formatting, so a library lacking a whole class is ruled
+//! out in one arm. The class follows from the row's body, while `support!`
+//! still sees the row name for arms ruling out a single row.
+//!
+//! [`bench_sets!`] does the same for benches timing many operands per
+//! iteration, drawn from named operand sets such as the edge-case corpus or
+//! seeded random streams, without checking results, and [`bench_ints!`]
+//! does it for conversions between the formats and the integer types.
+
+// The helpers are only invoked from within `#[cfg(test)]` modules.
+#![cfg_attr(not(test), allow(unused_macros))]
+
+macro_rules! bench_matrix {
+    (
+        formats {
+            $($format:ident: $ty:ty {
+                $(
+                    $row:ident $(= $body:ident)?($($arg:expr),*) => $expected:tt
+                    $(raises($($flag:ident),*))?,
+                )*
+            })*
+        }
+        backends $backends:tt
+    ) => {
+        $(
+            #[cfg(test)]
+            mod $format {
+                $(
+                    bench_row!(
+                        $format,
+                        $ty,
+                        [$row $(= $body)?],
+                        ($($arg),*),
+                        $expected,
+                        [$($($flag),*)?],
+                        $backends
+                    );
+                )*
+            }
+        )*
+    };
+}
+
+macro_rules! bench_row {
+    ($format:ident, $ty:ty, [$row:ident], $($rest:tt)*) => {
+        bench_row!($format, $ty, [$row = $row], $($rest)*);
+    };
+    (
+        $format:ident,
+        $ty:ty,
+        [$row:ident = $body:ident],
+        $args:tt,
+        $expected:tt,
+        $flags:tt,
+        $backends:tt
+    ) => {
+        mod $row {
+            bench_modes!($format, $ty, $row, $body, $args, $expected, $flags, $backends);
+        }
+    };
+}
+
+macro_rules! bench_modes {
+    (
+        $format:ident,
+        $ty:ty,
+        $row:ident,
+        $body:ident,
+        $args:tt,
+        { $($mode:ident: $expected:expr),* $(,)? },
+        $flags:tt,
+        $backends:tt
+    ) => {
+        $(
+            mod $mode {
+                use crate::backend::*;
+                use crate::format::*;
+                use test::Bencher;
+
+                bench_backends!(
+                    $format, $ty, $row, $body, $mode, $args, $expected, $flags, $backends
+                );
+            }
+        )*
+    };
+    (
+        $format:ident,
+        $ty:ty,
+        $row:ident,
+        $body:ident,
+        $args:tt,
+        $expected:expr,
+        $flags:tt,
+        $backends:tt
+    ) => {
+        bench_modes!(
+            $format,
+            $ty,
+            $row,
+            $body,
+            $args,
+            { rne: $expected, rtz: $expected, rup: $expected, rdn: $expected, rna: $expected },
+            $flags,
+            $backends
+        );
+    };
+}
+
+macro_rules! bench_backends {
+    (
+        $format:ident,
+        $ty:ty,
+        $row:ident,
+        $body:ident,
+        $mode:ident,
+        $args:tt,
+        $expected:expr,
+        $flags:tt,
+        { $($name:ident: $backend:ty,)* }
+    ) => {
+        $(
+            op_class!(
+                $body,
+                $name,
+                $format,
+                $row,
+                $mode,
+                bench_fn($name, $backend, $ty, $body, $mode, $args, $expected, $flags)
+            );
+        )*
+    };
+}
+
+macro_rules! bench_fn {
+    (
+        supported(
+            $name:ident,
+            $backend:ty,
+            $ty:ty,
+            $op:ident,
+            $mode:ident,
+            ($($arg:expr),*),
+            $expected:expr,
+            [$($flag:ident),*]
+        )
+    ) => {
+        #[bench]
+        fn $name(b: &mut Bencher) {
+            crate::bench::$op::<$ty, $backend>(
+                b,
+                rounding_mode!($mode),
+                $($arg,)*
+                $expected,
+                crate::env::ExceptionFlags::NONE $(| exception_flag!($flag))*
+            );
+        }
+    };
+    (unsupported($name:ident, $($rest:tt)*)) => {
+        #[bench]
+        #[ignore = "unsupported"]
+        fn $name(_: &mut Bencher) {}
+    };
+    (incorrect($name:ident, $($rest:tt)*)) => {
+        #[bench]
+        #[ignore = "incorrect"]
+        fn $name(_: &mut Bencher) {}
+    };
+}
+
+/// Maps the short mode names used in the table to [`RoundingMode`]s.
+///
+/// [`RoundingMode`]: crate::env::RoundingMode
+macro_rules! rounding_mode {
+    (rne) => {
+        crate::env::RoundingMode::TiesToEven
+    };
+    (rtz) => {
+        crate::env::RoundingMode::TowardZero
+    };
+    (rup) => {
+        crate::env::RoundingMode::TowardPositive
+    };
+    (rdn) => {
+        crate::env::RoundingMode::TowardNegative
+    };
+    (rna) => {
+        crate::env::RoundingMode::TiesToAway
+    };
+}
+
+/// Maps the flag names used in the table to [`ExceptionFlags`].
+///
+/// [`ExceptionFlags`]: crate::env::ExceptionFlags
+macro_rules! exception_flag {
+    (invalid) => {
+        crate::env::ExceptionFlags::INVALID
+    };
+    (divide_by_zero) => {
+        crate::env::ExceptionFlags::DIVIDE_BY_ZERO
+    };
+    (overflow) => {
+        crate::env::ExceptionFlags::OVERFLOW
+    };
+    (underflow) => {
+        crate::env::ExceptionFlags::UNDERFLOW
+    };
+    (inexact) => {
+        crate::env::ExceptionFlags::INEXACT
+    };
+}
+
+/// Expands lists of formats, operations, operand sets and backends into one
+/// `#[bench]` per combination, named
+/// `<kind>::<format>::<operation>::<set>::<backend>`.
+///
+/// Each kind of set names the module of `crate::bench` holding its benchmark
+/// bodies and the module providing its sets, which are passed to the bodies
+/// as function items: `corpus: edge_cases { zero, .. }` benches
+/// `crate::bench::corpus::add(b, crate::edge_cases::zero::<F>)` and so on.
+/// These benches run in the default rounding mode and consult `op_class!` with
+/// `rne`.
+macro_rules! bench_sets {
+    (
+        formats $formats:tt
+        operations $ops:tt
+        sets { $($kind:ident: $source:ident $sets:tt,)* }
+        backends $backends:tt
+    ) => {
+        $(
+            #[cfg(test)]
+            mod $kind {
+                sets_formats!($kind, $source, $formats, $ops, $sets, $backends);
+            }
+        )*
+    };
+}
+
+macro_rules! sets_formats {
+    (
+        $kind:ident,
+        $source:ident,
+        { $($format:ident: $ty:ty,)* },
+        $ops:tt,
+        $sets:tt,
+        $backends:tt
+    ) => {
+        $(
+            mod $format {
+                sets_operations!($kind, $source, $format, $ty, $ops, $sets, $backends);
+            }
+        )*
+    };
+}
+
+macro_rules! sets_operations {
+    (
+        $kind:ident,
+        $source:ident,
+        $format:ident,
+        $ty:ty,
+        { $($op:ident,)* },
+        $sets:tt,
+        $backends:tt
+    ) => {
+        $(
+            mod $op {
+                sets_sets!($kind, $source, $format, $ty, $op, $sets, $backends);
+            }
+        )*
+    };
+}
+
+macro_rules! sets_sets {
+    (
+        $kind:ident,
+        $source:ident,
+        $format:ident,
+        $ty:ty,
+        $op:ident,
+        { $($set:ident,)* },
+        $backends:tt
+    ) => {
+        $(
+            mod $set {
+                use crate::backend::*;
+                use crate::format::*;
+                use test::Bencher;
+
+                sets_backends!($kind, $source, $format, $ty, $op, $set, $backends);
+            }
+        )*
+    };
+}
+
+macro_rules! sets_backends {
+    (
+        $kind:ident,
+        $source:ident,
+        $format:ident,
+        $ty:ty,
+        $op:ident,
+        $set:ident,
+        { $($name:ident: $backend:ty,)* }
+    ) => {
+        $(
+            op_class!(
+                $op,
+                $name,
+                $format,
+                $op,
+                rne,
+                set_fn($name, $backend, $kind, $source, $ty, $op, $set)
+            );
+        )*
+    };
+}
+
+macro_rules! set_fn {
+    (
+        supported(
+            $name:ident,
+            $backend:ty,
+            $kind:ident,
+            $source:ident,
+            $ty:ty,
+            $op:ident,
+            $set:ident
+        )
+    ) => {
+        #[bench]
+        fn $name(b: &mut Bencher) {
+            crate::bench::$kind::$op::<$ty, $backend>(b, crate::$source::$set::<$ty>);
+        }
+    };
+    (unsupported($name:ident, $($rest:tt)*)) => {
+        #[bench]
+        #[ignore = "unsupported"]
+        fn $name(_: &mut Bencher) {}
+    };
+    (incorrect($name:ident, $($rest:tt)*)) => {
+        #[bench]
+        #[ignore = "incorrect"]
+        fn $name(_: &mut Bencher) {}
+    };
+}
+
+/// Expands to `#[cfg(test)] mod profile`, holding one bench per platform
+/// profile, format and backend that runs `crate::platform::cases` through
+/// `crate::bench::platform`, named `profile::<platform>::<format>::<backend>`.
+/// Whether a backend models a profile is decided by `platform_support!`,
+/// called like `support!` with the platform in place of the operation and
+/// rounding mode.
+macro_rules! bench_platforms {
+    (
+        platforms { $($platform:ident: $profile:ident,)* }
+        formats $formats:tt
+        backends $backends:tt
+    ) => {
+        #[cfg(test)]
+        mod profile {
+            $(
+                mod $platform {
+                    platforms_formats!($platform, $profile, $formats, $backends);
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! platforms_formats {
+    (
+        $platform:ident,
+        $profile:ident,
+        { $($format:ident: $ty:ty,)* },
+        $backends:tt
+    ) => {
+        $(
+            mod $format {
+                use crate::backend::*;
+                use crate::format::*;
+                use crate::platform::Platform;
+                use test::Bencher;
+
+                platforms_backends!($platform, $profile, $format, $ty, $backends);
+            }
+        )*
+    };
+}
+
+macro_rules! platforms_backends {
+    (
+        $platform:ident,
+        $profile:ident,
+        $format:ident,
+        $ty:ty,
+        { $($name:ident: $backend:ty,)* }
+    ) => {
+        $(
+            platform_support!(
+                $name,
+                $format,
+                $platform,
+                platform_fn($name, $backend, $ty, $profile)
+            );
+        )*
+    };
+}
+
+macro_rules! platform_fn {
+    (supported($name:ident, $backend:ty, $ty:ty, $profile:ident)) => {
+        #[bench]
+        fn $name(b: &mut Bencher) {
+            let cases = crate::platform::cases::<$ty>();
+            crate::bench::platform::<$ty, $backend>(b, Platform::$profile, &cases);
+        }
+    };
+    (unsupported($name:ident, $($rest:tt)*)) => {
+        #[bench]
+        #[ignore = "unsupported"]
+        fn $name(_: &mut Bencher) {}
+    };
+}
+
+/// Expands a table of formats, integer types and conversions into one
+/// `#[bench]` per rounding mode and backend, named
+/// `int::<format>::<int>::<from|to>::<mode>::<backend>`. `from` rows convert
+/// an integer operand to the format through `crate::bench::from_int` and `to`
+/// rows an encoding to the integer type through `crate::bench::to_int`, with
+/// expected results and flags given as for [`bench_matrix!`]. Whether a
+/// combination exists is decided by `int_support!`, called like `support!`
+/// with the integer type and direction in place of the operation.
+macro_rules! bench_ints {
+    (
+        formats {
+            $($format:ident: $ty:ty {
+                $($int:ident {
+                    $($dir:ident($arg:expr) => $expected:tt $(raises($($flag:ident),*))?,)*
+                })*
+            })*
+        }
+        backends $backends:tt
+    ) => {
+        #[cfg(test)]
+        mod int {
+            $(
+                mod $format {
+                    $(
+                        mod $int {
+                            $(
+                                mod $dir {
+                                    ints_modes!(
+                                        $format,
+                                        $ty,
+                                        $int,
+                                        $dir,
+                                        $arg,
+                                        $expected,
+                                        [$($($flag),*)?],
+                                        $backends
+                                    );
+                                }
+                            )*
+                        }
+                    )*
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! ints_modes {
+    (
+        $format:ident,
+        $ty:ty,
+        $int:ident,
+        $dir:ident,
+        $arg:expr,
+        { $($mode:ident: $expected:expr),* $(,)? },
+        $flags:tt,
+        $backends:tt
+    ) => {
+        $(
+            mod $mode {
+                use crate::backend::*;
+                use crate::format::*;
+                use test::Bencher;
+
+                ints_backends!($format, $ty, $int, $dir, $mode, $arg, $expected, $flags, $backends);
+            }
+        )*
+    };
+    (
+        $format:ident,
+        $ty:ty,
+        $int:ident,
+        $dir:ident,
+        $arg:expr,
+        $expected:expr,
+        $flags:tt,
+        $backends:tt
+    ) => {
+        ints_modes!(
+            $format,
+            $ty,
+            $int,
+            $dir,
+            $arg,
+            { rne: $expected, rtz: $expected, rup: $expected, rdn: $expected, rna: $expected },
+            $flags,
+            $backends
+        );
+    };
+}
+
+macro_rules! ints_backends {
+    (
+        $format:ident,
+        $ty:ty,
+        $int:ident,
+        $dir:ident,
+        $mode:ident,
+        $arg:expr,
+        $expected:expr,
+        $flags:tt,
+        { $($name:ident: $backend:ty,)* }
+    ) => {
+        $(
+            int_support!(
+                $name,
+                $format,
+                $int,
+                $dir,
+                $mode,
+                int_fn($name, $backend, $ty, $int, $dir, $mode, $arg, $expected, $flags)
+            );
+        )*
+    };
+}
+
+macro_rules! int_fn {
+    (
+        supported(
+            $name:ident,
+            $backend:ty,
+            $ty:ty,
+            $int:ty,
+            from,
+            $mode:ident,
+            $arg:expr,
+            $expected:expr,
+            [$($flag:ident),*]
+        )
+    ) => {
+        #[bench]
+        fn $name(b: &mut Bencher) {
+            crate::bench::from_int::<$ty, $int, $backend>(
+                b,
+                rounding_mode!($mode),
+                $arg,
+                $expected,
+                crate::env::ExceptionFlags::NONE $(| exception_flag!($flag))*
+            );
+        }
+    };
+    (
+        supported(
+            $name:ident,
+            $backend:ty,
+            $ty:ty,
+            $int:ty,
+            to,
+            $mode:ident,
+            $arg:expr,
+            $expected:expr,
+            [$($flag:ident),*]
+        )
+    ) => {
+        #[bench]
+        fn $name(b: &mut Bencher) {
+            crate::bench::to_int::<$ty, $int, $backend>(
+                b,
+                rounding_mode!($mode),
+                $arg,
+                $expected,
+                crate::env::ExceptionFlags::NONE $(| exception_flag!($flag))*
+            );
+        }
+    };
+    (unsupported($name:ident, $($rest:tt)*)) => {
+        #[bench]
+        #[ignore = "unsupported"]
+        fn $name(_: &mut Bencher) {}
+    };
+}
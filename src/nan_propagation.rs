@@ -0,0 +1,182 @@
+//! Which NaN each backend returns.
+//!
+//! IEEE 754 only requires an operation on NaNs to return a quiet NaN, which
+//! should carry the payload of one of its NaN operands, and to signal invalid
+//! when one of them is signaling. Which operand wins, whether the sign comes
+//! along, and whether the payload survives at all are up to the
+//! implementation, and hardware differs: x86 propagates the first NaN
+//! operand's payload, RISC-V always returns the canonical NaN. This runs every
+//! operation on combinations of quiet and signaling NaNs with distinct
+//! payloads and signs, plus the invalid operations that create NaNs, and
+//! records what each backend makes of them.
+
+use crate::backend::{Backends, Op};
+use crate::env::{ExceptionFlags, FpEnv};
+use crate::format::Format;
+use crate::platform::Platform;
+
+/// Where the NaN a backend returned came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Source {
+    /// It carries the payload of the operand at this index.
+    Operand(usize),
+    /// Its payload is empty.
+    Default,
+    /// Its payload matches none of the operands.
+    Other,
+    /// The result is not a NaN at all.
+    Number,
+}
+
+/// One backend's result for one row.
+#[derive(Clone, Debug)]
+pub struct Outcome<F: Format> {
+    pub bits: F::Bits,
+    pub source: Source,
+    /// Whether the result is a quiet NaN; a signaling NaN passed through
+    /// unchanged is not.
+    pub quiet: bool,
+    /// Whether invalid was signaled, for backends that report flags.
+    pub invalid: Option<bool>,
+}
+
+/// Operands with the names they are shown under.
+pub type Operands<F> = Vec<(&'static str, <F as Format>::Bits)>;
+
+/// An operation on named operands, and every backend's outcome.
+pub struct Row<F: Format> {
+    pub op: Op,
+    pub operands: Operands<F>,
+    /// One entry per backend, in [`Backends::backends`] order; `None` where
+    /// the backend lacks the operation.
+    pub outcomes: Vec<Option<Outcome<F>>>,
+}
+
+/// The payload of a NaN: its trailing significand without the quiet bit.
+fn payload<F: Format>(bits: F::Bits) -> u128 {
+    F::widen(bits) & (F::quiet_bit() - 1)
+}
+
+fn nan<F: Format>(negative: bool, quiet: bool, payload: u128) -> F::Bits {
+    let quiet = if quiet { F::quiet_bit() } else { 0 };
+    F::encode(negative, F::max_exponent(), quiet | payload)
+}
+
+fn one<F: Format>(negative: bool) -> F::Bits {
+    F::encode(negative, F::bias(), 0)
+}
+
+/// NaNs with distinct payloads, both signs and both kinds, and 1.0.
+fn nan_operands<F: Format>() -> Operands<F> {
+    vec![
+        ("+qNaN:1", nan::<F>(false, true, 1)),
+        ("-qNaN:2", nan::<F>(true, true, 2)),
+        ("+sNaN:3", nan::<F>(false, false, 3)),
+        ("-sNaN:4", nan::<F>(true, false, 4)),
+        ("1", one::<F>(false)),
+    ]
+}
+
+/// Operations creating a NaN from operands that are not NaNs.
+fn invalid_operations<F: Format>() -> Vec<(Op, Operands<F>)> {
+    let inf = ("inf", F::infinity(false));
+    let zero = ("0", F::encode(false, 0, 0));
+    let minus_one = ("-1", one::<F>(true));
+    let one = ("1", one::<F>(false));
+    vec![
+        (Op::Add, vec![inf, ("-inf", F::infinity(true))]),
+        (Op::Sub, vec![inf, inf]),
+        (Op::Mul, vec![zero, inf]),
+        (Op::Div, vec![zero, zero]),
+        (Op::Div, vec![inf, inf]),
+        (Op::Sqrt, vec![minus_one]),
+        (Op::Fma, vec![zero, inf, one]),
+        (Op::Rem, vec![inf, one]),
+        (Op::Rem, vec![one, zero]),
+    ]
+}
+
+/// Every tuple of `arity` operands with at least one NaN.
+fn nan_tuples<F: Format>(arity: usize) -> Vec<Operands<F>> {
+    let operands = nan_operands::<F>();
+    let mut tuples = vec![vec![]];
+    for _ in 0..arity {
+        tuples = tuples
+            .into_iter()
+            .flat_map(|tuple| {
+                operands.iter().map(move |&operand| {
+                    let mut tuple = tuple.clone();
+                    tuple.push(operand);
+                    tuple
+                })
+            })
+            .collect();
+    }
+    tuples.retain(|tuple| tuple.iter().any(|&(_, bits)| F::is_nan(bits)));
+    tuples
+}
+
+fn source<F: Format>(bits: F::Bits, operands: &[(&'static str, F::Bits)]) -> Source {
+    if !F::is_nan(bits) {
+        return Source::Number;
+    } else if payload::<F>(bits) == 0 {
+        return Source::Default;
+    }
+    operands
+        .iter()
+        .position(|&(_, operand)| F::is_nan(operand) && payload::<F>(operand) == payload::<F>(bits))
+        .map_or(Source::Other, Source::Operand)
+}
+
+/// Each of `ops` on every combination of NaN operands, followed by the
+/// invalid operations among `ops`.
+pub fn cases<F: Format>(ops: &[Op]) -> Vec<(Op, Operands<F>)> {
+    let mut cases = vec![];
+    for &op in ops {
+        cases.extend(
+            nan_tuples::<F>(op.arity())
+                .into_iter()
+                .map(|tuple| (op, tuple)),
+        );
+    }
+    let invalid = invalid_operations::<F>().into_iter();
+    cases.extend(invalid.filter(|(op, _)| ops.contains(op)));
+    cases
+}
+
+/// Runs [`cases`] through every backend of `F` on `platform`, which only
+/// affects backends modelling it.
+pub fn run<F: Backends>(ops: &[Op], platform: Platform) -> Vec<Row<F>> {
+    let backends = F::backends();
+    cases::<F>(ops)
+        .into_iter()
+        .map(|(op, operands)| {
+            let bits: Vec<_> = operands.iter().map(|&(_, bits)| bits).collect();
+            let outcomes = backends
+                .iter()
+                .map(|backend| {
+                    let mut env = FpEnv {
+                        platform,
+                        ..FpEnv::default()
+                    };
+                    let result = backend.apply(op, &bits, &mut env)?;
+                    Some(Outcome {
+                        bits: result,
+                        source: source::<F>(result, &operands),
+                        quiet: F::is_nan(result) && F::widen(result) & F::quiet_bit() != 0,
+                        invalid: if backend.exception_flags {
+                            Some(env.flags.contains(ExceptionFlags::INVALID))
+                        } else {
+                            None
+                        },
+                    })
+                })
+                .collect();
+            Row {
+                op,
+                operands,
+                outcomes,
+            }
+        })
+        .collect()
+}
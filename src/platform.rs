@@ -0,0 +1,94 @@
+//! Platform profiles: the choices IEEE 754 leaves to implementations.
+//!
+//! Whether underflow is detected before or after rounding, and which NaN an
+//! operation returns, differ between instruction sets. A [`Platform`] names
+//! one such set of choices; it travels in the [`FpEnv`] so that backends able
+//! to model it can configure themselves, and backends list the profiles they
+//! model in [`SoftFloatBackend::PLATFORMS`].
+//!
+//! [`FpEnv`]: crate::env::FpEnv
+//! [`SoftFloatBackend::PLATFORMS`]: crate::backend::SoftFloatBackend::PLATFORMS
+
+use crate::backend::Op;
+use crate::format::Format;
+use crate::nan_propagation;
+
+/// When a nonzero result is tiny, i.e. may signal underflow.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tininess {
+    /// Its exact value is below the smallest normal number.
+    BeforeRounding,
+    /// Rounded to the format's precision with an unbounded exponent range, it
+    /// is below the smallest normal number.
+    AfterRounding,
+}
+
+/// Which NaN an operation returns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NanPropagation {
+    /// Always the canonical NaN: positive and quiet, with an empty payload.
+    Canonical,
+    /// The first NaN operand, quieted; invalid operations return the
+    /// negative default NaN.
+    FirstOperand,
+    /// The first signaling NaN operand, or else the first quiet one, quieted;
+    /// invalid operations return the positive default NaN.
+    SignalingFirst,
+}
+
+/// A named set of implementation choices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Platform {
+    /// Short name used in benchmark paths and reports, e.g. `riscv`.
+    pub name: &'static str,
+    pub tininess: Tininess,
+    pub nan_propagation: NanPropagation,
+}
+
+impl Platform {
+    /// RISC-V F/D/Q/Zfh.
+    pub const RISC_V: Platform = Platform {
+        name: "riscv",
+        tininess: Tininess::AfterRounding,
+        nan_propagation: NanPropagation::Canonical,
+    };
+
+    /// x86 SSE and AVX.
+    pub const X86: Platform = Platform {
+        name: "x86",
+        tininess: Tininess::AfterRounding,
+        nan_propagation: NanPropagation::FirstOperand,
+    };
+
+    /// ARM VFP and AArch64 with default NaN mode off.
+    pub const ARM: Platform = Platform {
+        name: "arm",
+        tininess: Tininess::BeforeRounding,
+        nan_propagation: NanPropagation::SignalingFirst,
+    };
+
+    pub const ALL: [Platform; 3] = [Platform::RISC_V, Platform::X86, Platform::ARM];
+}
+
+impl Default for Platform {
+    fn default() -> Platform {
+        Platform::RISC_V
+    }
+}
+
+/// Operations on which the profiles disagree: add, sub, mul and div on
+/// combinations of NaNs, and products of 1 + ulp and the largest subnormal
+/// number, which are tiny before rounding but not after.
+pub fn cases<F: Format>() -> Vec<(Op, Vec<F::Bits>)> {
+    let ops = [Op::Add, Op::Sub, Op::Mul, Op::Div];
+    let mut cases: Vec<_> = nan_propagation::cases::<F>(&ops)
+        .into_iter()
+        .map(|(op, operands)| (op, operands.iter().map(|&(_, bits)| bits).collect()))
+        .collect();
+    let largest_subnormal = F::encode(false, 0, F::mantissa_mask());
+    for &negative in &[false, true] {
+        let one_plus_ulp = F::encode(negative, F::bias(), 1);
+        cases.push((Op::Mul, vec![one_plus_ulp, largest_subnormal]));
+    }
+    cases
+}
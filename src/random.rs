@@ -0,0 +1,84 @@
+//! Seeded random operands for the stream benches.
+//!
+//! Operands come from [SplitMix64] seeded with [`SEED`], so every machine
+//! times exactly the same streams. Each distribution draws one encoding of
+//! the format per call.
+//!
+//! [SplitMix64]: https://prng.di.unimi.it/splitmix64.c
+
+use crate::format::Format;
+
+/// Seed of every stream.
+pub const SEED: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// SplitMix64, chosen for being tiny and fully specified rather than for
+/// statistical quality.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    pub fn next_u128(&mut self) -> u128 {
+        u128::from(self.next_u64()) << 64 | u128::from(self.next_u64())
+    }
+
+    /// A value in `0..n`. The modulo bias is negligible for the small `n`
+    /// used here.
+    pub fn below(&mut self, n: u128) -> u128 {
+        self.next_u128() % n
+    }
+}
+
+fn sign(rng: &mut Rng) -> bool {
+    rng.below(2) == 1
+}
+
+fn mantissa<F: Format>(rng: &mut Rng) -> u128 {
+    rng.below(F::mantissa_mask() + 1)
+}
+
+/// Any encoding, infinities and NaNs included.
+pub fn uniform_bits<F: Format>(rng: &mut Rng) -> F::Bits {
+    let negative = sign(rng);
+    let exponent = rng.below(F::max_exponent() + 1);
+    F::encode(negative, exponent, mantissa::<F>(rng))
+}
+
+/// Any finite encoding, zeros and subnormals included.
+pub fn uniform_finite<F: Format>(rng: &mut Rng) -> F::Bits {
+    let negative = sign(rng);
+    let exponent = rng.below(F::max_exponent());
+    F::encode(negative, exponent, mantissa::<F>(rng))
+}
+
+/// Normal numbers whose magnitude is log-uniform over the whole normal range.
+pub fn log_uniform<F: Format>(rng: &mut Rng) -> F::Bits {
+    let negative = sign(rng);
+    let exponent = 1 + rng.below(F::max_exponent() - 1);
+    F::encode(negative, exponent, mantissa::<F>(rng))
+}
+
+/// Positive numbers in [0.5, 2), where most real-world arithmetic happens.
+pub fn near_one<F: Format>(rng: &mut Rng) -> F::Bits {
+    let exponent = F::bias() - rng.below(2);
+    F::encode(false, exponent, mantissa::<F>(rng))
+}
+
+/// Three subnormals in four, the rest log-uniform normal numbers.
+pub fn subnormal_heavy<F: Format>(rng: &mut Rng) -> F::Bits {
+    if rng.below(4) == 0 {
+        return log_uniform::<F>(rng);
+    }
+    let negative = sign(rng);
+    F::encode(negative, 0, 1 + rng.below(F::mantissa_mask()))
+}
@@ -0,0 +1,290 @@
+//! Correctly rounded reference results, computed with MPFR.
+//!
+//! MPFR rounds to any precision but its exponent range is far wider than that
+//! of any format here, so [`apply`] emulates a format in three steps: it
+//! rounds to the format's precision with an unbounded exponent, moves the
+//! result onto the format's subnormal grid with `subnormalize`, and finally
+//! compares it with the format's largest finite number for overflow. The
+//! result comes with the exception flags IEEE 754 requires. Formats without
+//! infinities return NaN where the result would be infinite, as rustc_apfloat
+//! does.
+//!
+//! The encodings assume an implicit integer bit, so formats with an explicit
+//! one, the x87's, are rejected rather than compared against wrong bits.
+//!
+//! IEEE 754 leaves tininess detection and the bits of NaN results open; both
+//! follow the platform profile of the environment, see [`Platform`].
+//!
+//! [`Platform`]: crate::platform::Platform
+
+use crate::backend::Op;
+use crate::env::{ExceptionFlags, FpEnv, RoundingMode};
+use crate::format::Format;
+use crate::platform::{NanPropagation, Tininess};
+use rug::float::{Round, Special};
+use rug::ops::{AddAssignRound, DivAssignRound, MulAssignRound, SubAssignRound};
+use rug::{Float, Integer};
+use std::cmp::Ordering;
+
+/// Significant bits, the implicit leading bit included.
+fn precision<F: Format>() -> u32 {
+    F::MANTISSA_BITS + 1
+}
+
+/// Exponent of the smallest normal number in MPFR's convention, where
+/// significands are in [0.5, 1).
+fn normal_exp_min<F: Format>() -> i32 {
+    2 - F::bias() as i32
+}
+
+/// Exponent of the least significant bit of the subnormal numbers.
+fn quantum_min<F: Format>() -> i32 {
+    normal_exp_min::<F>() - precision::<F>() as i32
+}
+
+fn is_nan<F: Format>(bits: u128) -> bool {
+    F::is_nan(F::narrow(bits))
+}
+
+/// Formats without infinities have no signaling NaN either.
+fn is_signaling<F: Format>(bits: u128) -> bool {
+    F::INFINITIES && is_nan::<F>(bits) && bits & F::quiet_bit() == 0
+}
+
+/// The positive quiet NaN with an empty payload, or the only positive NaN of
+/// formats without infinities.
+fn default_nan<F: Format>() -> u128 {
+    if F::INFINITIES {
+        F::widen(F::infinity(false)) | F::quiet_bit()
+    } else {
+        F::sign_bit() - 1
+    }
+}
+
+/// The encoding of the largest finite number, just below infinity or the
+/// NaN taking its place.
+fn max_finite<F: Format>() -> u128 {
+    if F::INFINITIES {
+        F::widen(F::infinity(false)) - 1
+    } else {
+        default_nan::<F>() - 1
+    }
+}
+
+/// Panics for formats storing the integer bit, which [`precision`],
+/// [`to_float`] and [`to_bits`] do not handle.
+fn assert_implicit_integer_bit<F: Format>() {
+    assert!(
+        !F::EXPLICIT_INTEGER_BIT,
+        "no reference for {}, whose integer bit is explicit",
+        F::NAME
+    );
+}
+
+/// The value of a non-NaN encoding, exactly.
+fn to_float<F: Format>(bits: u128) -> Float {
+    let exponent = (bits >> F::MANTISSA_BITS & ((1 << F::EXPONENT_BITS) - 1)) as i32;
+    let mantissa = bits & ((1 << F::MANTISSA_BITS) - 1);
+    let value = if F::INFINITIES && bits & !F::sign_bit() == F::widen(F::infinity(false)) {
+        Float::with_val(precision::<F>(), Special::Infinity)
+    } else if exponent == 0 {
+        Float::with_val(precision::<F>(), Integer::from(mantissa)) << quantum_min::<F>()
+    } else {
+        let significand = Integer::from(mantissa | 1 << F::MANTISSA_BITS);
+        Float::with_val(precision::<F>(), significand) << (quantum_min::<F>() + exponent - 1)
+    };
+    if bits & F::sign_bit() != 0 {
+        -value
+    } else {
+        value
+    }
+}
+
+/// The encoding of a non-NaN value that is already on the format's grid.
+fn to_bits<F: Format>(value: &Float) -> u128 {
+    let sign = if value.is_sign_negative() {
+        F::sign_bit()
+    } else {
+        0
+    };
+    if value.is_infinite() {
+        return sign | F::widen(F::infinity(false));
+    } else if value.is_zero() {
+        return sign;
+    }
+    // The exponent field and the significand add up, so a normal number's
+    // implicit bit lands in the exponent field.
+    let quantum = (value.get_exp().unwrap() - precision::<F>() as i32).max(quantum_min::<F>());
+    let significand = Float::with_val(value.prec(), &*value.as_abs()) >> quantum;
+    let significand = significand.to_integer().unwrap().to_u128().unwrap();
+    let exponent = ((quantum - quantum_min::<F>()) as u128) << F::MANTISSA_BITS;
+    sign | (exponent + significand)
+}
+
+fn mpfr_round(mode: RoundingMode) -> Round {
+    match mode {
+        RoundingMode::TiesToEven | RoundingMode::TiesToAway => Round::Nearest,
+        RoundingMode::TowardZero => Round::Zero,
+        RoundingMode::TowardPositive => Round::Up,
+        RoundingMode::TowardNegative => Round::Down,
+    }
+}
+
+/// `op` rounded to `precision` bits, with an unbounded exponent range.
+fn compute(op: Op, operands: &[Float], precision: u32, round: Round) -> (Float, Ordering) {
+    let mut value = Float::with_val(precision, &operands[0]);
+    let ordering = match op {
+        Op::Add => value.add_assign_round(&operands[1], round),
+        Op::Sub => value.sub_assign_round(&operands[1], round),
+        Op::Mul => value.mul_assign_round(&operands[1], round),
+        Op::Div => value.div_assign_round(&operands[1], round),
+        Op::Sqrt => value.sqrt_round(round),
+        Op::Fma => value.mul_add_round(&operands[1], &operands[2], round),
+        Op::Rem => value.remainder_round(&operands[1], round),
+    };
+    (value, ordering)
+}
+
+/// `op` rounded onto the format's grid, subnormals included, with an
+/// unbounded exponent range above it.
+fn round<F: Format>(op: Op, operands: &[Float], round: Round) -> (Float, Ordering) {
+    let (mut value, ordering) = compute(op, operands, precision::<F>(), round);
+    let ordering = value.subnormalize_round(ordering, normal_exp_min::<F>(), round);
+    (value, ordering)
+}
+
+/// MPFR has no roundTiesToAway, but it only differs from roundTiesToEven on
+/// exact midpoints, which need at most one bit more than the format has.
+fn round_ties_to_away<F: Format>(op: Op, operands: &[Float]) -> (Float, Ordering) {
+    let (toward_zero, ordering) = round::<F>(op, operands, Round::Zero);
+    if ordering == Ordering::Equal {
+        return (toward_zero, ordering);
+    }
+    let (away, away_ordering) = round::<F>(op, operands, Round::AwayZero);
+    let (exact, exactness) = compute(op, operands, precision::<F>() + 2, Round::Zero);
+    let midpoint = Float::with_val(precision::<F>() + 2, &toward_zero + &away) >> 1;
+    if exactness == Ordering::Equal && exact == midpoint {
+        (away, away_ordering)
+    } else {
+        round::<F>(op, operands, Round::Nearest)
+    }
+}
+
+/// Whether `mode` rounds a result of the given sign away from zero when it
+/// overflows.
+fn overflows_to_infinity(mode: RoundingMode, negative: bool) -> bool {
+    match mode {
+        RoundingMode::TiesToEven | RoundingMode::TiesToAway => true,
+        RoundingMode::TowardZero => false,
+        RoundingMode::TowardPositive => !negative,
+        RoundingMode::TowardNegative => negative,
+    }
+}
+
+/// The NaN returned for NaN `operands`, or for an invalid operation on
+/// numbers if there are none.
+fn propagate_nan<F: Format>(operands: &[u128], propagation: NanPropagation) -> u128 {
+    let default = default_nan::<F>();
+    let first = match propagation {
+        NanPropagation::Canonical => return default,
+        NanPropagation::FirstOperand => operands.iter().find(|&&bits| is_nan::<F>(bits)),
+        NanPropagation::SignalingFirst => operands
+            .iter()
+            .find(|&&bits| is_signaling::<F>(bits))
+            .or_else(|| operands.iter().find(|&&bits| is_nan::<F>(bits))),
+    };
+    match (first, propagation) {
+        (Some(&nan), _) => nan | F::quiet_bit(),
+        (None, NanPropagation::FirstOperand) => F::sign_bit() | default,
+        (None, _) => default,
+    }
+}
+
+/// Whether a nonzero inexact result is tiny as the profile detects it.
+fn is_tiny<F: Format>(op: Op, operands: &[Float], tininess: Tininess, mode: RoundingMode) -> bool {
+    let (value, _) = match tininess {
+        // Truncation keeps the exact value's exponent, unless the format's
+        // precision suffices, in which case the result is exact anyway.
+        Tininess::BeforeRounding => compute(op, operands, precision::<F>(), Round::Zero),
+        Tininess::AfterRounding => compute(op, operands, precision::<F>(), mpfr_round(mode)),
+    };
+    value.get_exp().unwrap() < normal_exp_min::<F>()
+}
+
+/// The correctly rounded result of `op` on `operands` in `F`, in the rounding
+/// mode of `env`, raising the exception flags of the operation in `env`.
+pub fn apply<F: Format>(op: Op, operands: &[F::Bits], env: &mut FpEnv) -> F::Bits {
+    assert_implicit_integer_bit::<F>();
+    let operands: Vec<u128> = operands.iter().map(|&bits| F::widen(bits)).collect();
+    if operands.iter().any(|&bits| is_signaling::<F>(bits)) {
+        env.raise(ExceptionFlags::INVALID);
+    }
+    if operands.iter().any(|&bits| is_nan::<F>(bits)) {
+        return F::narrow(propagate_nan::<F>(&operands, env.platform.nan_propagation));
+    }
+
+    let operands: Vec<Float> = operands.iter().map(|&bits| to_float::<F>(bits)).collect();
+    let (value, ordering) = match env.rounding_mode {
+        RoundingMode::TiesToAway => round_ties_to_away::<F>(op, &operands),
+        mode => round::<F>(op, &operands, mpfr_round(mode)),
+    };
+    if value.is_nan() {
+        env.raise(ExceptionFlags::INVALID);
+        return F::narrow(propagate_nan::<F>(&[], env.platform.nan_propagation));
+    }
+    if op == Op::Div && operands[0].is_finite() && operands[1].is_zero() {
+        env.raise(ExceptionFlags::DIVIDE_BY_ZERO);
+    }
+
+    // Without infinities, results that would be infinite are NaNs of the
+    // same sign.
+    let negative = value.is_sign_negative();
+    let sign = if negative { F::sign_bit() } else { 0 };
+    let infinity = if F::INFINITIES {
+        F::widen(F::infinity(false))
+    } else {
+        default_nan::<F>()
+    };
+    if value.is_infinite() {
+        return F::narrow(sign | infinity);
+    }
+    if value.is_normal() && *value.as_abs() > to_float::<F>(max_finite::<F>()) {
+        env.raise(ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT);
+        return if overflows_to_infinity(env.rounding_mode, negative) {
+            F::narrow(sign | infinity)
+        } else {
+            F::narrow(sign | max_finite::<F>())
+        };
+    }
+    if ordering != Ordering::Equal {
+        env.raise(ExceptionFlags::INEXACT);
+        if is_tiny::<F>(op, &operands, env.platform.tininess, env.rounding_mode) {
+            env.raise(ExceptionFlags::UNDERFLOW);
+        }
+    }
+    F::narrow(to_bits::<F>(&value))
+}
+
+/// The value of a finite decimal literal in `F`, rounded to nearest, ties to
+/// even, for checking that formatted text parses back to the value it came
+/// from. `None` if MPFR rejects the text.
+pub fn parse<F: Format>(text: &str) -> Option<F::Bits> {
+    assert_implicit_integer_bit::<F>();
+    let parsed = Float::parse(text).ok()?;
+    let (mut value, ordering) = Float::with_val_round(precision::<F>(), parsed, Round::Nearest);
+    value.subnormalize_round(ordering, normal_exp_min::<F>(), Round::Nearest);
+    if value.is_normal() && *value.as_abs() > to_float::<F>(max_finite::<F>()) {
+        let sign = if value.is_sign_negative() {
+            F::sign_bit()
+        } else {
+            0
+        };
+        let infinity = if F::INFINITIES {
+            F::widen(F::infinity(false))
+        } else {
+            default_nan::<F>()
+        };
+        return Some(F::narrow(sign | infinity));
+    }
+    Some(F::narrow(to_bits::<F>(&value)))
+}